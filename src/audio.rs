@@ -51,6 +51,18 @@ impl Audio {
 		raylib::play_sound(self.currentSound.unwrap());
 	}
 
+	/// Play sound from the start, even if it's the one already playing
+	pub fn restart_sound( &mut self, sound: String ) {
+		if sound != self.currentSoundName || self.currentSound.is_none() {
+			self.play_sound(sound);
+			return;
+		}
+
+		raylib::stop_sound(self.currentSound.unwrap());
+		raylib::set_sound_volume(self.currentSound.unwrap(), data::get_master_volume() * data::get_sfx_volume());
+		raylib::play_sound(self.currentSound.unwrap());
+	}
+
 	/// Play music
 	pub fn play_music( &mut self, music: String ) {
 		if music == self.currentMusicName { return }
@@ -0,0 +1,249 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::collections::HashMap;
+
+use crate::{monsters::{MonsterAttacks, attacks::AreaAnchor}, raylib::vectors::Vector3, settings::Difficulty, utilities::random::Random};
+use super::{BattleData, BattleObjectType, TURN_SLOTS, damage, engine::BattleAction, pathfinding};
+
+
+//= Constants
+/// Score added by the lookahead strategy for each opposing monster an option would faint.
+const FAINT_BONUS: i32 = 25;
+
+
+//= Structures
+
+/// A destination and attack chosen for a monster's turn.
+#[derive(Clone)]
+pub struct AiChoice {
+	pub position: Vector3,
+	pub attack: MonsterAttacks,
+	pub cursor: Vector3,
+
+	/// Predicted total damage against opposing monsters.
+	pub damage: i32,
+	/// Predicted number of opposing monsters fainted.
+	pub fainted: i32,
+}
+
+
+//= Procedures
+
+/// Chooses a destination and attack for the input battle object using the strategy for the difficulty.
+/// <br>Easy picks randomly, Medium picks the most damage and Hard also avoids tiles the opponent can hit back on.
+/// <br>If no attack can reach an opposing monster, the attack is ``MonsterAttacks::None`` and the destination moves towards them.
+pub fn choose(battle: &BattleData, objectID: &str, difficulty: Difficulty, rng: &mut Random) -> Option<AiChoice> {
+	if battle.get_monster(objectID).is_none() { return None; }
+
+	let options = get_options(battle, objectID);
+	if options.len() == 0 { return approach(battle, objectID, rng); }
+
+	match difficulty {
+		Difficulty::Easy => {
+			return Some(options[rng.range(0, options.len() as i32 - 1) as usize].clone());
+		}
+		Difficulty::Medium => {
+			let scores: Vec<i32> = options.iter().map(|o| o.damage).collect();
+			return Some(pick_best(&options, &scores, rng));
+		}
+		Difficulty::Hard => {
+			let mut threats: HashMap<[i32;3], i32> = HashMap::new();
+			let mut scores: Vec<i32> = Vec::new();
+			for option in options.iter() {
+				let tile: [i32;3] = option.position.into();
+				if !threats.contains_key(&tile) { threats.insert(tile, get_threat(battle, objectID, option.position)); }
+				scores.push(option.damage + (option.fainted * FAINT_BONUS) - (threats[&tile] / 2));
+			}
+			return Some(pick_best(&options, &scores, rng));
+		}
+	}
+}
+
+/// Chooses an action for the monster in ``choosing_slot``, or a replacement if one of the player's monsters has fainted.
+/// <br>Monsters that can't reach anything stay where they are.
+pub fn choose_action(battle: &BattleData, difficulty: Difficulty, rng: &mut Random) -> BattleAction {
+	if battle.pending_replacement().is_some() {
+		let num = (0..4).find(|n| battle.can_switch_to(*n)).unwrap();
+		return BattleAction::Switch{num};
+	}
+
+	let slot = battle.choosing_slot();
+	if slot.is_none() { return BattleAction::Pass; }
+	let objectID = TURN_SLOTS[slot.unwrap()];
+	let choice = choose(battle, objectID, difficulty, rng);
+	if choice.is_some() {
+		let choice = choice.unwrap();
+		return BattleAction::Attack{position: choice.position, attack: choice.attack, cursor: choice.cursor};
+	}
+
+	let position = battle.objects.get(objectID).map(|o| o.position).unwrap_or(Vector3{x:-1.0,y:-1.0,z:-1.0});
+	return BattleAction::Attack{position, attack: MonsterAttacks::None, cursor: position};
+}
+
+/// Returns every tile the battle object can move to this turn, including where it currently stands.
+pub fn get_destinations(battle: &BattleData, objectID: &str) -> Vec<Vector3> {
+	let mut output: Vec<Vector3> = Vec::new();
+
+	let monster = battle.get_monster(objectID);
+	if monster.is_none() { return output; }
+	let isPlayer = is_player(battle, objectID);
+	let reachable = pathfinding::reachable_tiles(battle, objectID);
+
+	//* Scanned in a fixed order so choices don't depend on the map's order */
+	let columns = if isPlayer { 0..8 } else { 8..16 };
+	for x in columns {
+		for z in 0..8 {
+			if reachable.contains_key(&[x,0,z]) { output.push(Vector3::from([x,0,z])); }
+		}
+	}
+
+	return output;
+}
+
+/// Returns every destination, attack and cursor combination for the battle object that covers an opposing monster.
+pub fn get_options(battle: &BattleData, objectID: &str) -> Vec<AiChoice> {
+	let mut output: Vec<AiChoice> = Vec::new();
+
+	let monster = battle.get_monster(objectID).unwrap();
+	let isPlayer = is_player(battle, objectID);
+
+	for position in get_destinations(battle, objectID) {
+		for attack in monster.attacks.iter() {
+			if !BattleData::can_use_attack(attack, position, isPlayer) { continue; }
+
+			let cursors: Vec<Vector3>;
+			if attack.data().get_area(BattleData::local_position(position.into(), isPlayer)[0]).anchor == AreaAnchor::Cursor {
+				cursors = opposing_tiles(isPlayer);
+			} else { cursors = vec![position]; }
+
+			for cursor in cursors {
+				let (hits, damage, fainted) = predict(battle, objectID, attack, position, cursor);
+				if hits == 0 { continue; }
+
+				output.push(AiChoice {
+					position,
+					attack: attack.clone(),
+					cursor,
+
+					damage,
+					fainted,
+				});
+			}
+		}
+	}
+
+	return output;
+}
+
+/// Predicts how many opposing monsters an attack would hit, the total damage and how many would faint.
+pub fn predict(battle: &BattleData, objectID: &str, attack: &MonsterAttacks, position: Vector3, cursor: Vector3) -> (i32, i32, i32) {
+	let attacker = battle.get_monster(objectID).unwrap();
+	let isPlayer = is_player(battle, objectID);
+
+	let mut hits = 0;
+	let mut total = 0;
+	let mut fainted = 0;
+	for (id, object) in battle.objects.iter() {
+		if is_player(battle, id) == isPlayer { continue; }
+		let defender = battle.get_monster(id);
+		if defender.is_none() || defender.unwrap().is_fainted() { continue; }
+		if !BattleData::attack_covers(attack, position, cursor, object.position.into(), isPlayer) { continue; }
+
+		let damage = damage::calculate_damage(attacker, defender.unwrap(), attack, battle.terrain_modifier(attack, position));
+		hits += 1;
+		total += damage.min(defender.unwrap().hpCur);
+		if damage >= defender.unwrap().hpCur { fainted += 1; }
+	}
+
+	return (hits, total, fainted);
+}
+
+/// Returns the most damage any opposing monster could deal to the battle object if it stood on the input tile.
+/// <br>Opposing monsters are assumed to attack from where they currently stand.
+pub fn get_threat(battle: &BattleData, objectID: &str, tile: Vector3) -> i32 {
+	let defender = battle.get_monster(objectID).unwrap();
+	let isPlayer = is_player(battle, objectID);
+
+	let mut output = 0;
+	for (id, object) in battle.objects.iter() {
+		if is_player(battle, id) == isPlayer { continue; }
+		let attacker = battle.get_monster(id);
+		if attacker.is_none() || attacker.unwrap().is_fainted() { continue; }
+
+		for attack in attacker.unwrap().attacks.iter() {
+			if !BattleData::can_use_attack(attack, object.position, !isPlayer) { continue; }
+
+			let cursors: Vec<Vector3>;
+			if attack.data().get_area(BattleData::local_position(object.position.into(), !isPlayer)[0]).anchor == AreaAnchor::Cursor {
+				cursors = opposing_tiles(!isPlayer);
+			} else { cursors = vec![object.position]; }
+
+			if cursors.iter().any(|c| BattleData::attack_covers(attack, object.position, *c, tile.into(), !isPlayer)) {
+				output = output.max(damage::calculate_damage(attacker.unwrap(), defender, attack, battle.terrain_modifier(attack, object.position)));
+			}
+		}
+	}
+
+	return output;
+}
+
+/// Moves towards the row of the closest opposing monster that hasn't fainted, without attacking.
+fn approach(battle: &BattleData, objectID: &str, rng: &mut Random) -> Option<AiChoice> {
+	let destinations = get_destinations(battle, objectID);
+	if destinations.len() == 0 { return None; }
+	let isPlayer = is_player(battle, objectID);
+
+	let mut scores: Vec<i32> = Vec::new();
+	for tile in destinations.iter() {
+		let mut closest = i32::MAX;
+		for (id, object) in battle.objects.iter() {
+			if is_player(battle, id) == isPlayer { continue; }
+			let monster = battle.get_monster(id);
+			if monster.is_none() || monster.unwrap().is_fainted() { continue; }
+			closest = closest.min((object.position.z - tile.z).abs() as i32 + (object.position.x - tile.x).abs() as i32);
+		}
+		scores.push(-closest);
+	}
+
+	let choices: Vec<AiChoice> = destinations.iter().map(|d| AiChoice {
+		position: *d,
+		attack: MonsterAttacks::None,
+		cursor: *d,
+
+		damage: 0,
+		fainted: 0,
+	}).collect();
+	return Some(pick_best(&choices, &scores, rng));
+}
+
+/// Picks randomly between the options with the highest score.
+fn pick_best(options: &Vec<AiChoice>, scores: &Vec<i32>, rng: &mut Random) -> AiChoice {
+	let best = *scores.iter().max().unwrap();
+	let candidates: Vec<usize> = (0..options.len()).filter(|i| scores[*i] == best).collect();
+
+	return options[candidates[rng.range(0, candidates.len() as i32 - 1) as usize]].clone();
+}
+
+/// Returns every tile on the half opposing the input side.
+fn opposing_tiles(isPlayer: bool) -> Vec<Vector3> {
+	let mut output: Vec<Vector3> = Vec::new();
+
+	let columns = if isPlayer { 8..16 } else { 0..8 };
+	for x in columns {
+		for z in 0..8 { output.push(Vector3::from([x,0,z])); }
+	}
+
+	return output;
+}
+
+/// Checks whether the battle object belongs to the player.
+fn is_player(battle: &BattleData, objectID: &str) -> bool {
+	match battle.objects[objectID].objType {
+		BattleObjectType::PlayerMonster { .. } => return true,
+		_ => return false,
+	}
+}
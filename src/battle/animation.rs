@@ -0,0 +1,194 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::f32::consts::PI;
+
+use crate::{monsters::{MonsterAttacks, MonsterTypes, attacks::{AttackAnimation, AreaAnchor}}, raylib::vectors::Vector3, audio::Audio};
+use super::{BattleData, damage::AttackResult, pathfinding};
+
+
+//= Constants
+/// Frames taken to walk across each tile.
+pub const MOVE_FRAMES: i32 = 8;
+/// Frames taken by attacks without an animation, so their sound has time to play.
+pub const WAIT_FRAMES: i32 = 20;
+pub const LUNGE_FRAMES: i32 = 18;
+/// How far a lunge reaches towards the target, in tiles.
+pub const LUNGE_DISTANCE: f32 = 0.6;
+pub const PROJECTILE_FRAMES: i32 = 24;
+/// Height above the tiles projectiles fly at.
+pub const PROJECTILE_HEIGHT: f32 = 0.75;
+pub const HIT_FRAMES: i32 = 24;
+/// How far targets shake from side to side when hit, in tiles.
+pub const SHAKE_DISTANCE: f32 = 0.15;
+/// Frames targets are hidden and shown for while flashing.
+pub const FLASH_INTERVAL: i32 = 4;
+
+
+//= Enumerations
+
+/// What a step of a battle animation does.
+#[derive(Clone)]
+pub enum AnimationKind {
+	/// Walks along the path, ending on it's last tile.
+	Move{ path: Vec<Vector3> },
+	/// Waits in place.
+	Wait,
+	/// Leans towards the target and back.
+	Lunge{ target: Vector3 },
+	/// A projectile of the attack's type flies to the target.
+	Projectile{ target: Vector3, attackType: MonsterTypes },
+	/// The targets flash and shake.
+	Hit{ targets: Vec<String> },
+}
+
+
+//= Structures
+
+/// A timed step of a battle animation, performed by a battle object.
+#[derive(Clone)]
+pub struct AnimationStep {
+	pub objectID: String,
+	pub kind: AnimationKind,
+
+	pub duration: i32,
+	pub ticks: i32,
+	/// Played when the step starts.
+	pub sound: Option<String>,
+}
+
+
+//= Procedures
+
+impl AnimationStep {
+
+	/// Creates a step that hasn't started yet.
+	pub fn new(objectID: &str, kind: AnimationKind, duration: i32, sound: Option<String>) -> Self {
+		return AnimationStep {
+			objectID: objectID.to_string(),
+			kind,
+
+			duration,
+			ticks: 0,
+			sound,
+		}
+	}
+
+	/// Returns how far through the step is, from ``0.0`` to ``1.0``.
+	pub fn progress(&self) -> f32 {
+		return (self.ticks as f32 / self.duration.max(1) as f32).clamp(0.0, 1.0);
+	}
+
+	/// Returns how far the input battle object is drawn from it's position during the step.
+	pub fn offset(&self, objectID: &str, position: Vector3) -> Vector3 {
+		let progress = self.progress();
+
+		match &self.kind {
+			AnimationKind::Move { path } if objectID == self.objectID && path.len() > 1 => {
+				let distance = progress * (path.len() - 1) as f32;
+				let index = (distance.floor() as usize).min(path.len() - 2);
+				let current = path[index] + (path[index + 1] - path[index]) * (distance - index as f32);
+				return current - position;
+			}
+			AnimationKind::Lunge { target } if objectID == self.objectID => {
+				let mut direction = *target - position;
+				direction.y = 0.0;
+				let length = (direction.x * direction.x + direction.z * direction.z).sqrt();
+				if length == 0.0 { return Vector3::zero(); }
+				return direction * ((PI * progress).sin() * LUNGE_DISTANCE / length);
+			}
+			AnimationKind::Hit { targets } if targets.iter().any(|t| t == objectID) => {
+				return Vector3{x:(self.ticks as f32 * 1.3).sin() * SHAKE_DISTANCE * (1.0 - progress),y:0.0,z:0.0};
+			}
+			_ => return Vector3::zero(),
+		}
+	}
+
+	/// Checks whether the input battle object is hidden this frame because it's flashing.
+	pub fn flashing(&self, objectID: &str) -> bool {
+		match &self.kind {
+			AnimationKind::Hit { targets } => return targets.iter().any(|t| t == objectID) && (self.ticks / FLASH_INTERVAL) % 2 == 1,
+			_ => return false,
+		}
+	}
+
+	/// Returns where the projectile is and it's type, if the step has one.
+	pub fn projectile(&self, start: Vector3) -> Option<(Vector3, MonsterTypes)> {
+		match &self.kind {
+			AnimationKind::Projectile { target, attackType } => return Some((start + (*target - start) * self.progress(), *attackType)),
+			_ => return None,
+		}
+	}
+
+}
+
+impl BattleData {
+
+	/// Queues the animation of the current monster moving to the position and using the attack.
+	/// <br>Has to be called before the monster is moved, since it walks there from it's current tile.
+	pub fn queue_attack_animation(&mut self, objectID: &str, position: Vector3, attack: &MonsterAttacks, cursor: Vector3, results: &[AttackResult]) {
+		//* Move */
+		let path = pathfinding::find_path(self, objectID, position).unwrap_or(Vec::new());
+		if path.len() > 1 {
+			let duration = MOVE_FRAMES * (path.len() as i32 - 1);
+			self.animations.push_back(AnimationStep::new(objectID, AnimationKind::Move{ path }, duration, None));
+		}
+		if *attack == MonsterAttacks::None { return; }
+
+		//* Attack, aimed at the first target or where the attack lands */
+		let definition = attack.data();
+		let isPlayer = self.is_player(objectID);
+		let hits: Vec<String> = results.iter().filter(|r| !r.missed && r.target != "").map(|r| r.target.to_string()).collect();
+		let aimed = results.iter().find(|r| r.target != "").and_then(|r| self.objects.get(&r.target)).map(|o| o.position);
+		let target = match aimed {
+			Some(target) => target,
+			None if definition.get_area(BattleData::local_position(position.into(), isPlayer)[0]).anchor == AreaAnchor::Cursor => cursor,
+			None => position + Vector3{x:if isPlayer { 4.0 } else { -4.0 },y:0.0,z:0.0},
+		};
+
+		let sound = if definition.sound != "" { Some(definition.sound.to_string()) } else { None };
+		let step = match definition.animation {
+			AttackAnimation::None		=> AnimationStep::new(objectID, AnimationKind::Wait, WAIT_FRAMES, sound),
+			AttackAnimation::Lunge		=> AnimationStep::new(objectID, AnimationKind::Lunge{ target }, LUNGE_FRAMES, sound),
+			AttackAnimation::Projectile	=> AnimationStep::new(objectID, AnimationKind::Projectile{ target, attackType: definition.attackType }, PROJECTILE_FRAMES, sound),
+		};
+		self.animations.push_back(step);
+
+		//* Targets */
+		if hits.len() > 0 { self.animations.push_back(AnimationStep::new(objectID, AnimationKind::Hit{ targets: hits }, HIT_FRAMES, None)); }
+	}
+
+	/// Plays the current animation step, and ends the turn once the last one is done.
+	pub fn update_animations(&mut self, audio: &mut Audio) {
+		let step = self.animations.front_mut();
+		if step.is_none() { return; }
+		let step = step.unwrap();
+
+		//* Restarted so attacks in a row with the same sound each play it */
+		if step.ticks == 0 && step.sound.is_some() { audio.restart_sound(step.sound.clone().unwrap()); }
+		step.ticks += 1;
+		if step.ticks < step.duration { return; }
+
+		//* Walking finishes on the new tile, which the turn ending would move it to anyway */
+		let step = self.animations.pop_front().unwrap();
+		if let AnimationKind::Move { path } = &step.kind {
+			if self.objects.contains_key(&step.objectID) { self.objects.get_mut(&step.objectID).unwrap().position = *path.last().unwrap(); }
+		}
+
+		if self.animations.is_empty() { self.next_turn(); }
+	}
+
+}
+
+/// Returns the color projectiles of the input type are drawn with.
+pub fn type_color(attackType: MonsterTypes) -> raylib_ffi::Color {
+	match attackType {
+		MonsterTypes::Fire	=> return raylib_ffi::Color{r:255,g:120,b:40,a:255},
+		MonsterTypes::Water	=> return raylib_ffi::Color{r:70,g:140,b:255,a:255},
+		MonsterTypes::Grass	=> return raylib_ffi::Color{r:90,g:200,b:70,a:255},
+		_					=> return raylib_ffi::colors::WHITE,
+	}
+}
@@ -0,0 +1,154 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{collections::HashMap, fs::{read_dir, read_to_string}, str::FromStr};
+
+use crate::utilities::debug;
+
+
+//= Constants
+/// Folder arena layouts are loaded from.
+const ARENA_FOLDER: &str = "data/arenas";
+/// Damage multiplier for Grass attacks used from tall grass.
+pub const TALL_GRASS_BOOST: f32 = 1.5;
+/// Extra movement cost of stepping onto water tiles.
+pub const WATER_PENALTY: i32 = 1;
+/// Extra movement cost of each step of elevation climbed.
+pub const CLIMB_PENALTY: i32 = 1;
+/// Height of each step of elevation when drawn.
+pub const ELEVATION_HEIGHT: f32 = 0.5;
+
+
+//= Enumerations
+
+/// Effects a battle tile has on monsters using it.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Terrain {
+	Normal,
+	/// Boosts Grass attacks used from it.
+	TallGrass,
+	/// Costs more movement to step onto.
+	Water,
+}
+impl FromStr for Terrain {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"normal"		=> Ok(Terrain::Normal),
+			"tall_grass"	=> Ok(Terrain::TallGrass),
+			"water"			=> Ok(Terrain::Water),
+			_				=> Err(()),
+		}
+	}
+}
+
+
+//= Structures
+
+/// A tile of a battle arena.
+#[derive(Clone)]
+pub struct BattleTile {
+	pub model: String,
+	/// Nothing can stand on or move onto blocked tiles.
+	pub blocked: bool,
+	/// Height of the tile in steps.
+	pub elevation: i32,
+	pub terrain: Terrain,
+}
+
+
+//= Procedures
+
+impl BattleTile {
+
+	/// Creates a flat, open tile.
+	pub fn create(model: &str) -> Self {
+		return BattleTile {
+			model: model.to_string(),
+			blocked: false,
+			elevation: 0,
+			terrain: Terrain::Normal,
+		}
+	}
+
+}
+
+/// Loads every arena layout into a Hashmap indexed by their file name.
+/// <br>Each character of the layout is a tile from the file's legend, with rows going down the arena.
+/// <br>Files that can't be read are reported and skipped, so the other arenas still load.
+pub fn load() -> HashMap<String, HashMap<[i32;3], BattleTile>> {
+	let mut output: HashMap<String, HashMap<[i32;3], BattleTile>> = HashMap::new();
+
+	//* Attempt to read arena folder */
+	let folderResult = read_dir(ARENA_FOLDER);
+	if folderResult.is_err() {
+		debug::log("[ERROR] - Failed to find arena folder.\n");
+		return output;
+	}
+
+	for entry in folderResult.unwrap() {
+		if entry.is_err() {
+			debug::log("[ERROR] - Failed to read an entry of the arena folder.\n");
+			continue;
+		}
+		let path = entry.unwrap().path();
+		if path.extension().map(|e| e != "json").unwrap_or(true) { continue; }
+		let id = path.file_stem().unwrap().to_string_lossy().to_string();
+
+		//* Convert to JSON and read */
+		let fileResult = read_to_string(&path);
+		if fileResult.is_err() {
+			debug::log(&format!("[ERROR] - Failed to load arena \"{}\".\n", id));
+			continue;
+		}
+		let jsonResult = serde_json::from_str::<serde_json::Value>(&fileResult.unwrap());
+		if jsonResult.is_err() {
+			debug::log(&format!("[ERROR] - Arena \"{}\" isn't valid JSON.\n", id));
+			continue;
+		}
+		let jsonFile = jsonResult.unwrap();
+		if !jsonFile["legend"].is_object() || !jsonFile["layout"].is_array() {
+			debug::log(&format!("[ERROR] - Arena \"{}\" is missing it's legend or layout.\n", id));
+			continue;
+		}
+
+		let mut legend: HashMap<char, BattleTile> = HashMap::new();
+		for (key, value) in jsonFile["legend"].as_object().unwrap() {
+			let terrain = Terrain::from_str(value["terrain"].as_str().unwrap_or("normal"));
+			if key.chars().count() != 1 || terrain.is_err() || !value["model"].is_string() {
+				debug::log(&format!("[ERROR] - Arena \"{}\" has an invalid legend entry \"{}\".\n", id, key));
+				continue;
+			}
+
+			legend.insert(key.chars().next().unwrap(), BattleTile {
+				model: value["model"].as_str().unwrap().to_string(),
+				blocked: value["blocked"].as_bool().unwrap_or(false),
+				elevation: value["elevation"].as_i64().unwrap_or(0) as i32,
+				terrain: terrain.unwrap(),
+			});
+		}
+
+		let mut tiles: HashMap<[i32;3], BattleTile> = HashMap::new();
+		for (z, row) in jsonFile["layout"].as_array().unwrap().iter().enumerate() {
+			if !row.is_string() {
+				debug::log(&format!("[ERROR] - Arena \"{}\" has an invalid layout row {}.\n", id, z));
+				continue;
+			}
+			for (x, key) in row.as_str().unwrap().chars().enumerate() {
+				if key == ' ' { continue; }
+				if !legend.contains_key(&key) {
+					debug::log(&format!("[ERROR] - Arena \"{}\" uses \"{}\" which isn't in it's legend.\n", id, key));
+					continue;
+				}
+				tiles.insert([x as i32, 0, z as i32], legend[&key].clone());
+			}
+		}
+
+		output.insert(id, tiles);
+	}
+
+	return output;
+}
@@ -0,0 +1,275 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use crate::{monsters::{Monster, MonsterAttacks, MonsterConditions, AttackCategory, BattleStat, types::Effectiveness}, utilities::random::Random};
+
+
+//= Constants
+/// Damage multiplier of critical hits.
+pub const CRIT_MULTIPLIER: f32 = 1.5;
+/// Lowest percentage of damage a hit can roll.
+pub const VARIANCE_MIN: i32 = 85;
+
+
+//= Structures
+
+/// The outcome of an attack against a single target.
+#[derive(Clone)]
+pub struct AttackResult {
+	pub attacker: String,
+	pub target: String,
+	pub attack: MonsterAttacks,
+
+	pub damage: i32,
+	pub effectiveness: Effectiveness,
+	pub fainted: bool,
+	pub missed: bool,
+	pub critical: bool,
+	/// Condition inflicted on the target.
+	pub condition: Option<MonsterConditions>,
+	/// Stat stages that actually changed on the target.
+	pub statChanges: Vec<(BattleStat, i8)>,
+}
+
+
+//= Procedures
+
+impl AttackResult {
+
+	/// Creates a result for an attack that didn't hit anything.
+	pub fn miss(attacker: &str, attack: MonsterAttacks) -> Self {
+		return AttackResult {
+			attacker: attacker.to_string(),
+			target: "".to_string(),
+			attack,
+
+			damage: 0,
+			effectiveness: Effectiveness::Normal,
+			fainted: false,
+			missed: true,
+			critical: false,
+			condition: None,
+			statChanges: Vec::new(),
+		}
+	}
+
+}
+
+/// Calculates the damage the attacker would deal to the defender using the input attack.
+/// <br>``modifier`` is any extra multiplier from the battle, like terrain. The attacker's ability is applied here.
+pub fn calculate_damage(attacker: &Monster, defender: &Monster, attack: &MonsterAttacks, modifier: f32) -> i32 {
+	let definition = attack.data();
+	let power = definition.power;
+	if power <= 0 { return 0; }
+
+	let (attackStat, defenseStat) = match definition.category {
+		AttackCategory::Physical	=> (attacker.get_effective_stat(BattleStat::PhysicalAttack), defender.get_effective_stat(BattleStat::PhysicalDefense)),
+		AttackCategory::Special		=> (attacker.get_effective_stat(BattleStat::SpecialAttack), defender.get_effective_stat(BattleStat::SpecialDefense)),
+		AttackCategory::Status		=> return 0,
+	};
+
+	let levelScale = ((2.0 * attacker.level as f32) / 5.0).floor() + 2.0;
+	let ratio = attackStat as f32 / defenseStat.max(1) as f32;
+	let boost = attacker.ability.data().attack_multiplier(definition.attackType, attacker.hpCur, attacker.hpMax);
+	let multiplier = calculate_effectiveness(defender, attack) * boost * modifier;
+
+	return ((((levelScale * power as f32 * ratio) / 50.0).floor() + 2.0) * multiplier).floor() as i32;
+}
+
+/// Calculates the type multiplier of the input attack against the defender.
+/// <br>Status attacks are always ``1.0``.
+pub fn calculate_effectiveness(defender: &Monster, attack: &MonsterAttacks) -> f32 {
+	let definition = attack.data();
+	if definition.category == AttackCategory::Status { return 1.0; }
+
+	return definition.attackType.effectiveness(defender.types);
+}
+
+/// Applies the input attack from the attacker to the defender and reports the outcome.
+/// <br>Accuracy, critical hits and damage variance are rolled with the input generator.
+pub fn apply_attack(attackerID: &str, attacker: &Monster, targetID: &str, defender: &mut Monster, attack: &MonsterAttacks, modifier: f32, rng: &mut Random) -> AttackResult {
+	let definition = attack.data();
+
+	//* Accuracy */
+	if !rng.chance(definition.accuracy) {
+		let mut result = AttackResult::miss(attackerID, attack.clone());
+		result.target = targetID.to_string();
+		return result;
+	}
+
+	//* Critical hits and variance */
+	let mut critical = false;
+	let mut roll = 1.0;
+	if definition.category != AttackCategory::Status {
+		critical = rng.chance(definition.critRate);
+		roll = rng.range(VARIANCE_MIN, 100) as f32 / 100.0;
+		if critical { roll *= CRIT_MULTIPLIER; }
+	}
+
+	let effectiveness = Effectiveness::from(calculate_effectiveness(defender, attack));
+	let mut damage = calculate_damage(attacker, defender, attack, modifier * roll);
+	if damage < 1 && definition.category != AttackCategory::Status && effectiveness != Effectiveness::Immune { damage = 1; }
+	let fainted = defender.take_damage(damage);
+
+	//* Conditions */
+	let mut condition = None;
+	if !fainted && effectiveness != Effectiveness::Immune && definition.condition.is_some() {
+		let inflicted = definition.condition.as_ref().unwrap();
+		if rng.chance(inflicted.chance) {
+			defender.add_condition(inflicted.condition.clone());
+			condition = Some(inflicted.condition.clone());
+		}
+	}
+
+	//* Stat stages */
+	let mut statChanges: Vec<(BattleStat, i8)> = Vec::new();
+	if !fainted && effectiveness != Effectiveness::Immune {
+		for (stat, amount) in definition.statChanges.iter() {
+			let change = defender.change_stat_stage(*stat, *amount);
+			if change != 0 { statChanges.push((*stat, change)); }
+		}
+	}
+
+	return AttackResult {
+		attacker: attackerID.to_string(),
+		target: targetID.to_string(),
+		attack: attack.clone(),
+
+		damage,
+		effectiveness,
+		fainted,
+		missed: false,
+		critical,
+		condition,
+		statChanges,
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::monsters::tests::{create_monster, attack};
+
+	#[test]
+	fn damage_is_deterministic() {
+		let attacker = create_monster("mon_158", 20);
+		let defender = create_monster("mon_152", 20);
+		let damage = calculate_damage(&attacker, &defender, &attack("tackle"), 1.0);
+		assert!(damage > 0);
+		assert_eq!(damage, calculate_damage(&attacker, &defender, &attack("tackle"), 1.0));
+	}
+
+	#[test]
+	fn status_attacks_deal_no_damage() {
+		let attacker = create_monster("mon_158", 20);
+		let defender = create_monster("mon_152", 20);
+		assert_eq!(calculate_damage(&attacker, &defender, &attack("growl"), 1.0), 0);
+	}
+
+	#[test]
+	fn effectiveness_scales_damage() {
+		let attacker = create_monster("mon_158", 20);
+		let fire = create_monster("mon_155", 20);
+		let water = create_monster("mon_158", 20);
+		assert_eq!(calculate_effectiveness(&fire, &attack("watergun")), 2.0);
+		assert_eq!(calculate_effectiveness(&water, &attack("watergun")), 0.5);
+		assert_eq!(calculate_effectiveness(&fire, &attack("growl")), 1.0);
+
+		let strong = calculate_damage(&attacker, &fire, &attack("watergun"), 1.0);
+		let weak = calculate_damage(&attacker, &water, &attack("watergun"), 1.0);
+		assert!(strong > weak);
+	}
+
+	#[test]
+	fn higher_levels_deal_more_damage() {
+		let defender = create_monster("mon_152", 20);
+		let weak = calculate_damage(&create_monster("mon_158", 5), &defender, &attack("tackle"), 1.0);
+		let strong = calculate_damage(&create_monster("mon_158", 50), &defender, &attack("tackle"), 1.0);
+		assert!(strong > weak);
+	}
+
+	#[test]
+	fn modifier_scales_damage() {
+		let attacker = create_monster("mon_152", 30);
+		let defender = create_monster("mon_158", 30);
+		let normal = calculate_damage(&attacker, &defender, &attack("leafage"), 1.0);
+		assert!(calculate_damage(&attacker, &defender, &attack("leafage"), 1.5) > normal);
+	}
+
+	#[test]
+	fn attacks_remove_health() {
+		let attacker = create_monster("mon_158", 20);
+		let mut defender = create_monster("mon_152", 20);
+		let health = defender.hpCur;
+		let result = apply_attack("player_1", &attacker, "enemy_1", &mut defender, &attack("tackle"), 1.0, &mut Random::new(1));
+		assert_eq!(defender.hpCur, health - result.damage);
+		assert!(!result.fainted && !result.missed);
+
+		let result = apply_attack("player_1", &create_monster("mon_158", 100), "enemy_1", &mut create_monster("mon_152", 2), &attack("tackle"), 1.0, &mut Random::new(1));
+		assert!(result.fainted);
+	}
+
+	#[test]
+	fn accuracy_rolls() {
+		let attacker = create_monster("mon_155", 20);
+		let defender = create_monster("mon_152", 100);
+		let mut rng = Random::new(5);
+		let mut misses = 0;
+		for _ in 0..500 {
+			assert!(!apply_attack("a", &attacker, "b", &mut defender.clone(), &attack("tackle"), 1.0, &mut rng).missed);
+			if apply_attack("a", &attacker, "b", &mut defender.clone(), &attack("ember"), 1.0, &mut rng).missed { misses += 1; }
+		}
+		assert!(misses > 0 && misses < 250);
+	}
+
+	#[test]
+	fn variance_and_critical_hits() {
+		let attacker = create_monster("mon_158", 50);
+		let defender = create_monster("mon_152", 100);
+		let tackle = attack("tackle");
+		let lowest = calculate_damage(&attacker, &defender, &tackle, VARIANCE_MIN as f32 / 100.0);
+		let highest = calculate_damage(&attacker, &defender, &tackle, 1.0);
+
+		let mut rng = Random::new(9);
+		let mut criticals = 0;
+		for _ in 0..1000 {
+			let result = apply_attack("a", &attacker, "b", &mut defender.clone(), &tackle, 1.0, &mut rng);
+			if result.critical {
+				criticals += 1;
+				assert!(result.damage >= lowest && result.damage <= calculate_damage(&attacker, &defender, &tackle, CRIT_MULTIPLIER));
+			} else {
+				assert!(result.damage >= lowest && result.damage <= highest);
+			}
+		}
+		assert!(criticals > 0);
+	}
+
+	#[test]
+	fn same_seed_same_result() {
+		let attacker = create_monster("mon_158", 30);
+		let defender = create_monster("mon_152", 30);
+		let mut a = Random::new(11);
+		let mut b = Random::new(11);
+		for _ in 0..50 {
+			let first = apply_attack("a", &attacker, "b", &mut defender.clone(), &attack("watergun"), 1.0, &mut a);
+			let second = apply_attack("a", &attacker, "b", &mut defender.clone(), &attack("watergun"), 1.0, &mut b);
+			assert_eq!((first.damage, first.missed, first.critical), (second.damage, second.missed, second.critical));
+		}
+	}
+
+	#[test]
+	fn hits_deal_at_least_one() {
+		let attacker = create_monster("mon_158", 1);
+		let defender = create_monster("mon_152", 100);
+		let mut rng = Random::new(2);
+		for _ in 0..50 {
+			let result = apply_attack("a", &attacker, "b", &mut defender.clone(), &attack("tackle"), 0.01, &mut rng);
+			assert!(result.damage >= 1);
+		}
+	}
+
+}
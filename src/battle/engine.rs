@@ -0,0 +1,606 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use crate::{monsters::{self, MonsterAttacks, BattleStat, types::Effectiveness, abilities::{AbilityEffect, AbilityTarget}}, raylib::vectors::Vector3, utilities::random::Random};
+use super::{BattleData, BattleType, BattleObjectType, BattleOutcome, PlayerBattleState, TURN_SLOTS, damage, wild, pathfinding, log::{BattleEvent, LogName}};
+
+
+//= Enumerations
+
+/// An action chosen for a monster's turn.
+/// <br>The battle rules only ever change through these, so they can come from the player, the AI or a simulation.
+#[derive(Clone, PartialEq)]
+pub enum BattleAction {
+	/// Moves to ``position`` and uses the attack aimed at ``cursor``.
+	/// <br>``MonsterAttacks::None`` only moves.
+	Attack{position: Vector3, attack: MonsterAttacks, cursor: Vector3},
+	/// Sends out the team member at ``num``.
+	/// <br>Also used to replace a fainted monster, which doesn't use up the turn.
+	Switch{num: i32},
+	/// Runs from a wild battle.
+	Flee,
+	/// Throws a capture at the wild monster.
+	Capture,
+	/// Ends the turn without doing anything, used in place of an action that wasn't allowed.
+	Pass,
+}
+
+
+//= Procedures
+
+impl BattleData {
+
+	/// Chooses the action of the monster in ``choosing_slot``, or sends out a replacement if one is needed first.
+	/// <br>Once every monster has chosen, the round is played out in turn order.
+	/// <br>Returns false without changing anything if the action isn't allowed.
+	pub fn apply_action(&mut self, action: &BattleAction) -> bool {
+		if self.outcome.is_some() { return false; }
+
+		//* Fainted monsters are replaced before the round continues */
+		let replacement = self.pending_replacement();
+		if replacement.is_some() {
+			match action {
+				BattleAction::Switch { num } if self.can_switch_to(*num) => {
+					self.switch_monster(replacement.unwrap(), *num);
+					self.record(action);
+					self.continue_round();
+					return true;
+				}
+				_ => return false,
+			}
+		}
+
+		//* Every monster chooses it's action before the round's turns start */
+		let slot = self.choosing_slot();
+		if slot.is_none() || !self.can_choose(TURN_SLOTS[slot.unwrap()], action) { return false; }
+
+		self.record(action);
+		self.pendingActions[slot.unwrap()] = Some(action.clone());
+		self.playerState = PlayerBattleState::Movement;
+		self.movementCursor = Vector3{x:-1.0,y:-1.0,z:-1.0};
+
+		//* The order comes from the actions chosen, so priority attacks go first this round */
+		if self.choosing_slot().is_none() {
+			self.recalc_turn_order();
+			self.turnCur = 0;
+			self.continue_round();
+		}
+		return true;
+	}
+
+	/// Checks whether the battle object can choose the action for this round.
+	pub fn can_choose(&self, objectID: &str, action: &BattleAction) -> bool {
+		let monster = self.get_monster(objectID);
+		if monster.is_none() || monster.unwrap().is_fainted() { return false; }
+		let isPlayer = self.is_player(objectID);
+
+		match action {
+			BattleAction::Attack { position, attack, .. } => {
+				if !self.can_move_to(objectID, *position) { return false; }
+				return *attack == MonsterAttacks::None || (monster.unwrap().attacks.contains(attack) && BattleData::can_use_attack(attack, *position, isPlayer));
+			}
+			BattleAction::Switch { num } => return self.can_switch_to(*num) && !self.pendingActions.contains(&Some(action.clone())),
+			BattleAction::Pass => return true,
+			BattleAction::Flee | BattleAction::Capture => {
+				let wild = match self.battleType {
+					BattleType::Wild { .. } => true,
+					_ => false,
+				};
+				return isPlayer && wild;
+			}
+		}
+	}
+
+	/// Performs the pending action of the monster whose turn it is.
+	fn take_turn(&mut self) {
+		let (objectID, _) = self.get_current_monster();
+		let slot = TURN_SLOTS.iter().position(|s| *s == objectID).unwrap();
+		let action = self.pendingActions[slot].clone().unwrap_or(BattleAction::Pass);
+
+		match action {
+			BattleAction::Attack { position, attack, cursor } => {
+				let mut results: Vec<damage::AttackResult> = Vec::new();
+				if attack != MonsterAttacks::None { results = self.resolve_attack(&objectID, attack.clone(), position, cursor); }
+
+				self.movementCursor = position;
+				//* Animated battles end the turn once the attack has played out, see ``update_animations`` */
+				if self.animated { self.queue_attack_animation(&objectID, position, &attack, cursor, &results); }
+				if self.animations.is_empty() { self.next_turn(); }
+			}
+			BattleAction::Switch { num } => {
+				//* A replacement may have sent the team member out already */
+				if self.can_switch_to(num) { self.switch_monster(&objectID, num); }
+				self.next_turn();
+			}
+			BattleAction::Pass => self.next_turn(),
+			BattleAction::Flee => self.try_flee(&objectID),
+			BattleAction::Capture => self.try_capture(),
+		}
+	}
+
+	/// Adds a performed action to the replay being recorded.
+	fn record(&mut self, action: &BattleAction) {
+		if self.recording.is_some() { self.recording.as_mut().unwrap().actions.push(action.clone()); }
+	}
+
+	/// Adds the event to the battle log unless the battle is ``silent``.
+	pub fn log(&mut self, event: BattleEvent) {
+		if !self.silent { self.events.push(event); }
+	}
+
+	/// Returns the name the battle log uses for the monster represented by the battle object.
+	pub fn log_name(&self, objectID: &str) -> Option<LogName> {
+		return self.get_monster(objectID).map(|m| LogName::new(m, self.is_player(objectID)));
+	}
+
+	/// Attempts to run from a wild battle, using up the turn if it fails.
+	fn try_flee(&mut self, objectID: &str) {
+		let player = self.get_monster(objectID).unwrap().clone();
+		let wild = self.get_monster("enemy_1");
+		if wild.is_none() { return; }
+
+		let chance = wild::flee_chance(&player, wild.unwrap(), self.fleeAttempts);
+		self.fleeAttempts += 1;
+		if self.rng.chance(chance) {
+			self.log(BattleEvent::Flee{ success: true });
+			self.outcome = Some(BattleOutcome::Fled);
+			return;
+		}
+
+		self.log(BattleEvent::Flee{ success: false });
+		self.next_turn();
+	}
+
+	/// Attempts to capture the wild monster, using up the turn if it fails.
+	fn try_capture(&mut self) {
+		let wild = self.get_monster("enemy_1");
+		if wild.is_none() { return; }
+		let wild = wild.unwrap().clone();
+
+		if self.rng.chance(wild::capture_chance(&wild)) {
+			self.log(BattleEvent::Capture{ monster: LogName::new(&wild, false), success: true });
+			let mut captured = wild;
+			captured.reset_stat_stages();
+			self.captured = Some(captured);
+			self.outcome = Some(BattleOutcome::Captured);
+			return;
+		}
+
+		self.log(BattleEvent::Capture{ monster: LogName::new(&wild, false), success: false });
+		self.next_turn();
+	}
+
+	/// Checks whether the player's team member can be sent out, meaning it's healthy and not already on the field.
+	pub fn can_switch_to(&self, num: i32) -> bool {
+		if num < 0 || num > 3 { return false; }
+		let monster = self.playerTeam.0[num as usize].as_ref();
+		if monster.is_none() || monster.unwrap().is_fainted() { return false; }
+
+		for (_, object) in self.objects.iter() {
+			if let BattleObjectType::PlayerMonster { num: active, .. } = object.objType {
+				if active == num { return false; }
+			}
+		}
+		return true;
+	}
+
+	/// Replaces the monster represented by the battle object with another member of it's team, keeping it's tile.
+	/// <br>The outgoing monster's stat stages are reset.
+	pub fn switch_monster(&mut self, objectID: &str, num: i32) {
+		let outgoing = self.get_monster_mut(objectID);
+		if outgoing.is_none() { return; }
+		outgoing.unwrap().reset_stat_stages();
+
+		let object = self.objects.get_mut(objectID).unwrap();
+		match object.objType.clone() {
+			BattleObjectType::PlayerMonster { .. } => {
+				let monster = self.playerTeam.0[num as usize].as_ref();
+				if monster.is_none() { return; }
+				object.objType = BattleObjectType::PlayerMonster{num, species: monster.unwrap().species.clone()};
+				self.participants[num as usize] = true;
+			}
+			BattleObjectType::EnemyMonster { team, .. } => {
+				let monster = self.enemyTeams[team].0[num as usize].as_ref();
+				if monster.is_none() { return; }
+				object.objType = BattleObjectType::EnemyMonster{num, team, species: monster.unwrap().species.clone()};
+			}
+			BattleObjectType::Delete => return,
+		}
+
+		//* A monster sent out partway through a round waits for the next one to act */
+		let slot = TURN_SLOTS.iter().position(|s| *s == objectID);
+		if slot.is_some() && self.pendingActions[slot.unwrap()].is_some() { self.pendingActions[slot.unwrap()] = Some(BattleAction::Pass); }
+
+		let monster = self.log_name(objectID).unwrap();
+		self.log(BattleEvent::SentOut{ monster });
+		self.trigger_on_enter(objectID);
+	}
+
+	/// Returns the player battle object whose monster has fainted and can be replaced, if there is one.
+	pub fn pending_replacement(&self) -> Option<&'static str> {
+		if !(0..4).any(|n| self.can_switch_to(n)) { return None; }
+
+		for objectID in TURN_SLOTS[0..2].iter() {
+			let monster = self.get_monster(objectID);
+			if monster.is_some() && monster.unwrap().is_fainted() { return Some(objectID); }
+		}
+		return None;
+	}
+
+	/// Sends in the next healthy monster from each enemy team whose monster on the field has fainted.
+	fn replace_fainted_enemies(&mut self) {
+		for objectID in TURN_SLOTS[2..4].iter() {
+			let monster = self.get_monster(objectID);
+			if monster.is_none() || !monster.unwrap().is_fainted() { continue; }
+
+			let team = match self.objects[*objectID].objType {
+				BattleObjectType::EnemyMonster { team, .. } => team,
+				_ => continue,
+			};
+			let next = (0..4).find(|n| {
+				let reserve = self.enemyTeams[team].0[*n].as_ref();
+				return reserve.is_some() && !reserve.unwrap().is_fainted() && !self.objects.values().any(|o| match o.objType {
+					BattleObjectType::EnemyMonster { num, team: active, .. } => active == team && num == *n as i32,
+					_ => false,
+				});
+			});
+			if next.is_some() { self.switch_monster(objectID, next.unwrap() as i32); }
+		}
+	}
+
+	/// Checks whether the battle object can move to the tile this turn.
+	/// <br>There has to be a path to it within the object's move distance, see ``pathfinding::step_cost``.
+	pub fn can_move_to(&self, objectID: &str, tile: Vector3) -> bool {
+		if self.get_monster(objectID).is_none() { return false; }
+		return pathfinding::find_path(self, objectID, tile).is_some();
+	}
+
+	/// Checks whether a battle object other than the input one is standing on the tile, or has chosen to move there this round.
+	pub fn is_occupied(&self, tile: Vector3, objectID: &str) -> bool {
+		for (id, object) in self.objects.iter() {
+			if id != objectID && object.position == tile { return true; }
+		}
+		for (i, action) in self.pendingActions.iter().enumerate() {
+			if TURN_SLOTS[i] == objectID { continue; }
+			if let Some(BattleAction::Attack { position, .. }) = action {
+				if *position == tile { return true; }
+			}
+		}
+		return false;
+	}
+
+	pub fn next_turn(&mut self) {
+		self.playerState = PlayerBattleState::Movement;
+		let (str, _) = self.get_current_monster();
+		if self.objects.contains_key(&str) && self.movementCursor != (Vector3{x:-1.0,y:-1.0,z:-1.0}) {
+			self.objects.get_mut(&str).unwrap().position = self.movementCursor;
+		}
+		self.movementCursor = Vector3{x:-1.0,y:-1.0,z:-1.0};
+
+		//* End of turn conditions */
+		let monster = self.get_monster_mut(&str);
+		if monster.is_some() && !monster.as_ref().unwrap().is_fainted() {
+			let damage = monster.unwrap().tick_conditions();
+			if damage > 0 {
+				let target = self.log_name(&str).unwrap();
+				self.log(BattleEvent::ConditionDamage{ target: target.clone(), damage });
+				if self.get_monster(&str).unwrap().is_fainted() { self.log(BattleEvent::Fainted{ target }); }
+			}
+			self.trigger_on_turn_end(&str);
+		}
+		self.check_outcome();
+		if self.outcome.is_none() { self.replace_fainted_enemies(); }
+
+		self.turnCur += 1;
+		//* If turn is invalid, reset round */
+		if self.turnCur >= 4 || self.turnOrder[self.turnCur as usize] == -1 {
+			self.log(BattleEvent::RoundEnd{ round: self.roundTotal + 1 });
+			self.roundTotal += 1;
+			self.turnCur = 0;
+
+			//* End of round conditions */
+			let ids: Vec<String> = self.objects.keys().cloned().collect();
+			for id in ids {
+				let monster = self.get_monster_mut(&id);
+				if monster.is_some() { monster.unwrap().decay_conditions(); }
+			}
+
+			//* Every monster chooses again for the next round */
+			self.pendingActions = [None, None, None, None];
+		}
+
+		self.continue_round();
+	}
+
+	/// Does the pending action of the monster whose turn it is, skipping the turn if it's monster is missing or fainted.
+	/// <br>Waits while actions or a replacement are being chosen.
+	fn continue_round(&mut self) {
+		if self.outcome.is_some() || self.pending_replacement().is_some() || self.choosing_slot().is_some() { return; }
+
+		let (str, _) = self.get_current_monster();
+		if self.get_monster(&str).map(|m| m.is_fainted()).unwrap_or(true) { self.next_turn(); }
+		else { self.take_turn(); }
+	}
+
+	/// Recalculates the turn order from the active monsters' effective speed and the priority brackets of their chosen attacks.
+	pub fn recalc_turn_order(&mut self) {
+		let mut monsters: [Option<monsters::Monster>;4] = [None, None, None, None];
+		let mut priorities = [0;4];
+		for i in 0..4 {
+			let monster = self.get_monster(TURN_SLOTS[i]);
+			if monster.is_some() && !monster.unwrap().is_fainted() { monsters[i] = monster.cloned(); }
+			if let Some(BattleAction::Attack { attack, .. }) = &self.pendingActions[i] {
+				if *attack != MonsterAttacks::None { priorities[i] = attack.data().priority; }
+			}
+		}
+
+		self.turnOrder = calc_turn_order(monsters, priorities, &mut self.rng);
+	}
+
+	/// Returns the turn slot that still has to choose it's action for the round, in slot order.
+	/// <br>The round's turns only start once every active monster has chosen.
+	pub fn choosing_slot(&self) -> Option<usize> {
+		if self.outcome.is_some() || self.pending_replacement().is_some() { return None; }
+
+		for i in 0..4 {
+			let monster = self.get_monster(TURN_SLOTS[i]);
+			if monster.is_some() && !monster.unwrap().is_fainted() && self.pendingActions[i].is_none() { return Some(i); }
+		}
+		return None;
+	}
+
+	/// Ends the battle as a win or loss once every monster on one side has fainted.
+	pub fn check_outcome(&mut self) {
+		if self.outcome.is_some() { return; }
+
+		let wiped = |team: &monsters::MonsterTeam| -> bool {
+			return team.0.iter().all(|m| m.is_none() || m.as_ref().unwrap().is_fainted());
+		};
+
+		if wiped(&self.playerTeam) { self.outcome = Some(BattleOutcome::Loss); }
+		else if wiped(&self.enemyTeams[0]) && wiped(&self.enemyTeams[1]) { self.outcome = Some(BattleOutcome::Win); }
+
+		if self.outcome.is_some() { self.log(BattleEvent::Outcome{ outcome: self.outcome.unwrap() }); }
+	}
+
+	/// Resolves an attack from the input battle object against every opposing object in it's area.
+	/// <br>The results are returned and added to ``results``.
+	pub fn resolve_attack(&mut self, attackerID: &str, attack: MonsterAttacks, position: Vector3, cursor: Vector3) -> Vec<damage::AttackResult> {
+		let mut output: Vec<damage::AttackResult> = Vec::new();
+
+		let attacker = self.get_monster(attackerID);
+		if attacker.is_none() { return output; }
+		let attacker = attacker.unwrap().clone();
+		let isPlayer = match self.objects[attackerID].objType {
+			BattleObjectType::PlayerMonster { .. } => true,
+			_ => false,
+		};
+
+		//* Find targets in a consistent order */
+		let mut targets: Vec<String> = Vec::new();
+		for (id, object) in self.objects.iter() {
+			let opposing = match object.objType {
+				BattleObjectType::PlayerMonster { .. }	=> !isPlayer,
+				BattleObjectType::EnemyMonster { .. }	=> isPlayer,
+				BattleObjectType::Delete				=> false,
+			};
+			if opposing && BattleData::attack_covers(&attack, position, cursor, object.position.into(), isPlayer) { targets.push(id.to_string()); }
+		}
+		targets.sort();
+
+		//* Apply attack */
+		let modifier = self.terrain_modifier(&attack, position);
+		for id in targets {
+			let (defender, rng) = self.get_monster_and_rng(&id);
+			if defender.is_none() || defender.as_ref().unwrap().is_fainted() { continue; }
+			output.push(damage::apply_attack(attackerID, &attacker, &id, defender.unwrap(), &attack, modifier, rng));
+		}
+		self.log(BattleEvent::Attack{ user: LogName::new(&attacker, isPlayer), attack: attack.clone() });
+		for result in output.iter() {
+			self.log_result(result);
+			if !result.missed && !result.fainted { self.trigger_on_hit(&result.target, attackerID, &attack); }
+		}
+		if output.len() == 0 {
+			output.push(damage::AttackResult::miss(attackerID, attack.clone()));
+			self.log(BattleEvent::Missed{ target: None });
+		}
+		self.check_outcome();
+
+		self.results.append(&mut output.clone());
+		return output;
+	}
+
+	/// Adds the events for one target of an attack to the battle log.
+	fn log_result(&mut self, result: &damage::AttackResult) {
+		let target = self.log_name(&result.target);
+		if target.is_none() { return; }
+		let target = target.unwrap();
+
+		if result.missed {
+			self.log(BattleEvent::Missed{ target: Some(target) });
+			return;
+		}
+		if result.damage > 0 || result.effectiveness == Effectiveness::Immune {
+			self.log(BattleEvent::Hit{ target: target.clone(), damage: result.damage, effectiveness: result.effectiveness, critical: result.critical });
+		}
+		if result.condition.is_some() { self.log(BattleEvent::Condition{ target: target.clone(), condition: result.condition.clone().unwrap() }); }
+		for (stat, amount) in result.statChanges.iter() {
+			self.log(BattleEvent::StatChange{ target: target.clone(), stat: *stat, amount: *amount });
+		}
+		if result.fainted { self.log(BattleEvent::Fainted{ target }); }
+	}
+
+	/// Applies the on-enter ability of the monster represented by the battle object.
+	pub fn trigger_on_enter(&mut self, objectID: &str) {
+		let monster = self.get_monster(objectID);
+		if monster.is_none() || monster.unwrap().is_fainted() { return; }
+		let effect = monster.unwrap().ability.data().onEnter.clone();
+		if effect.is_some() { self.apply_ability_effect(objectID, &effect.unwrap(), None); }
+	}
+
+	/// Applies the on-hit ability of the monster represented by the battle object after it was hit by the attacker.
+	fn trigger_on_hit(&mut self, objectID: &str, attackerID: &str, attack: &MonsterAttacks) {
+		let monster = self.get_monster(objectID);
+		if monster.is_none() { return; }
+		let effect = monster.unwrap().ability.data().onHit.clone();
+		if effect.is_none() { return; }
+		let effect = effect.unwrap();
+
+		if effect.category.is_some() && effect.category.unwrap() != attack.data().category { return; }
+		self.apply_ability_effect(objectID, &effect, Some(attackerID));
+	}
+
+	/// Applies the end of turn ability of the monster represented by the battle object.
+	fn trigger_on_turn_end(&mut self, objectID: &str) {
+		let monster = self.get_monster(objectID);
+		if self.outcome.is_some() || monster.is_none() || monster.unwrap().is_fainted() { return; }
+		let effect = monster.unwrap().ability.data().onTurnEnd.clone();
+		if effect.is_some() { self.apply_ability_effect(objectID, &effect.unwrap(), None); }
+	}
+
+	/// Applies an ability's effect from the user to it's targets.
+	/// <br>Opposing targets are taken in turn slot order so the rolls stay in a consistent order.
+	fn apply_ability_effect(&mut self, userID: &str, effect: &AbilityEffect, attackerID: Option<&str>) {
+		let ability = self.get_monster(userID).unwrap().ability.clone();
+		let isPlayer = self.is_player(userID);
+
+		let targets: Vec<String> = match effect.target {
+			AbilityTarget::User		=> vec![userID.to_string()],
+			AbilityTarget::Attacker	=> attackerID.map(|a| vec![a.to_string()]).unwrap_or(Vec::new()),
+			AbilityTarget::Enemies	=> TURN_SLOTS.iter().filter(|id| self.objects.contains_key(**id) && self.is_player(id) != isPlayer).map(|id| id.to_string()).collect(),
+		};
+
+		let mut events: Vec<BattleEvent> = Vec::new();
+		for id in targets {
+			let name = self.log_name(&id);
+			let (target, rng) = self.get_monster_and_rng(&id);
+			if target.is_none() || target.as_ref().unwrap().is_fainted() { continue; }
+			let target = target.unwrap();
+			let name = name.unwrap();
+
+			if effect.condition.is_some() && rng.chance(effect.condition.as_ref().unwrap().chance) {
+				let condition = effect.condition.as_ref().unwrap().condition.clone();
+				target.add_condition(condition.clone());
+				events.push(BattleEvent::Condition{ target: name.clone(), condition });
+			}
+			for (stat, amount) in effect.statChanges.iter() {
+				let change = target.change_stat_stage(*stat, *amount);
+				if change != 0 { events.push(BattleEvent::StatChange{ target: name.clone(), stat: *stat, amount: change }); }
+			}
+		}
+
+		//* The ability is only announced if it did something */
+		if events.len() > 0 {
+			let user = self.log_name(userID).unwrap();
+			self.log(BattleEvent::Ability{ user, ability });
+			for event in events { self.log(event); }
+		}
+	}
+
+	pub fn get_current_monster(&self) -> (String, i8) {
+		let mut current = self.turnOrder[self.turnCur as usize] + 1;
+		let mon: String;
+		if current > 2 {
+			mon = "enemy_".to_string();
+			current -= 2;
+		} else { mon = "player_".to_string(); }
+
+		return (mon + &current.to_string(), current);
+	}
+
+}
+
+/// Calculates the order monsters act in from their turn slots.
+/// <br>Higher priority brackets go first, then higher effective speed, with ties broken by the input generator.
+pub fn calc_turn_order(monsters: [Option<monsters::Monster>;4], priorities: [i32;4], rng: &mut Random) -> [i8;4] {
+	let mut result: [i8;4] = [-1,-1,-1,-1];
+
+	//* Tie-breakers are rolled for every slot so the sequence doesn't depend on who's active */
+	let mut active: Vec<(i8, i32, i32, u64)> = Vec::new();
+	for x in 0..4 {
+		let tieBreak = rng.next();
+		if monsters[x].is_none() { continue; }
+		active.push((x as i8, priorities[x], monsters[x].as_ref().unwrap().get_effective_stat(BattleStat::Speed), tieBreak));
+	}
+	active.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)).then(a.3.cmp(&b.3)));
+
+	for (i, entry) in active.iter().enumerate() { result[i] = entry.0; }
+
+	return result;
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{battle::ArenaType, monsters::{MonsterTeam, tests::{create_monster, attack}}};
+
+	#[test]
+	fn faster_monsters_go_first() {
+		let monsters = [Some(create_monster("mon_158", 10)), None, Some(create_monster("mon_155", 30)), Some(create_monster("mon_152", 20))];
+		let order = calc_turn_order(monsters, [0;4], &mut Random::new(1));
+		assert_eq!(order, [2, 3, 0, -1]);
+	}
+
+	#[test]
+	fn priority_beats_speed() {
+		let monsters = [Some(create_monster("mon_158", 10)), None, Some(create_monster("mon_155", 30)), None];
+		let order = calc_turn_order(monsters, [1, 0, 0, 0], &mut Random::new(1));
+		assert_eq!(order, [0, 2, -1, -1]);
+	}
+
+	#[test]
+	fn speed_stages_change_the_order() {
+		let mut slow = create_monster("mon_158", 20);
+		let fast = create_monster("mon_155", 22);
+		assert_eq!(calc_turn_order([Some(slow.clone()), None, Some(fast.clone()), None], [0;4], &mut Random::new(1)), [2, 0, -1, -1]);
+
+		slow.change_stat_stage(BattleStat::Speed, 2);
+		assert_eq!(calc_turn_order([Some(slow), None, Some(fast), None], [0;4], &mut Random::new(1)), [0, 2, -1, -1]);
+	}
+
+	#[test]
+	fn ties_follow_the_seed() {
+		let monsters = [Some(create_monster("mon_155", 20)), None, Some(create_monster("mon_155", 20)), None];
+		let mut orders: Vec<[i8;4]> = Vec::new();
+		for seed in 0..20 {
+			let order = calc_turn_order(monsters.clone(), [0;4], &mut Random::new(seed));
+			assert_eq!(order, calc_turn_order(monsters.clone(), [0;4], &mut Random::new(seed)));
+			orders.push(order);
+		}
+		assert!(orders.contains(&[0, 2, -1, -1]) && orders.contains(&[2, 0, -1, -1]));
+	}
+
+	#[test]
+	fn chosen_priority_orders_the_round() {
+		let mut team = MonsterTeam::new();
+		team.add_member(create_monster("mon_158", 10));
+		let mut battle = BattleData::init();
+		battle.animated = false;
+		battle.start_seeded_battle(BattleType::Wild{ monster: create_monster("mon_155", 30), arena: ArenaType::Field }, &team, 1);
+
+		//* Nothing happens until every monster has chosen, and chosen tiles are taken */
+		assert_eq!(battle.choosing_slot(), Some(0));
+		let position = Vector3{x:5.0,y:0.0,z:4.0};
+		let enemy = battle.objects["enemy_1"].position;
+		assert!(!battle.apply_action(&BattleAction::Attack{ position, attack: attack("ember"), cursor: enemy }));
+		assert!(battle.apply_action(&BattleAction::Attack{ position, attack: attack("aquajet"), cursor: enemy }));
+		assert_eq!(battle.choosing_slot(), Some(2));
+		assert!(battle.is_occupied(position, "player_2"));
+		assert!(battle.results.is_empty());
+
+		//* The slower monster goes first with it's higher priority attack */
+		assert!(battle.apply_action(&BattleAction::Attack{ position: enemy, attack: attack("leer"), cursor: enemy }));
+		assert_eq!(battle.turnOrder, [0, 2, -1, -1]);
+		assert_eq!(battle.results.iter().map(|r| r.attacker.as_str()).collect::<Vec<&str>>(), ["player_1", "enemy_1"]);
+		assert!(battle.objects["player_1"].position == position);
+
+		//* Then the next round is chosen */
+		assert_eq!(battle.roundTotal, 1);
+		assert_eq!(battle.choosing_slot(), Some(0));
+	}
+
+}
@@ -0,0 +1,161 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{collections::HashMap, fs};
+
+use crate::{monsters::{Monster, MonsterAttacks, MonsterAbilities, MonsterConditions, BattleStat, types::Effectiveness}, utilities::debug};
+use super::BattleOutcome;
+
+
+//= Constants
+/// Folder battle logs are saved to.
+pub const LOG_FOLDER: &str = "logs";
+/// Lines of the battle log shown at once.
+pub const VISIBLE_LINES: usize = 6;
+
+
+//= Enumerations
+
+/// Something that happened during a battle.
+/// <br>Kept in ``BattleData::events`` and only turned into text when the log is drawn or saved, so it follows the language.
+#[derive(Clone)]
+pub enum BattleEvent {
+	/// The battle started with the input seed.
+	Start{ seed: u64 },
+	SentOut{ monster: LogName },
+	Attack{ user: LogName, attack: MonsterAttacks },
+	/// A target took damage, or was immune to the attack.
+	Hit{ target: LogName, damage: i32, effectiveness: Effectiveness, critical: bool },
+	/// ``None`` when the attack had no targets.
+	Missed{ target: Option<LogName> },
+	Condition{ target: LogName, condition: MonsterConditions },
+	StatChange{ target: LogName, stat: BattleStat, amount: i8 },
+	ConditionDamage{ target: LogName, damage: i32 },
+	Ability{ user: LogName, ability: MonsterAbilities },
+	Fainted{ target: LogName },
+	RoundEnd{ round: i32 },
+	Flee{ success: bool },
+	Capture{ monster: LogName, success: bool },
+	Outcome{ outcome: BattleOutcome },
+}
+
+
+//= Structures
+
+/// A monster's name as it was when the event happened.
+#[derive(Clone)]
+pub struct LogName {
+	/// Species ID, or the nickname.
+	pub name: String,
+	pub isNickname: bool,
+	pub isPlayer: bool,
+}
+
+
+//= Procedures
+
+impl LogName {
+
+	/// Creates the name of the input monster.
+	pub fn new(monster: &Monster, isPlayer: bool) -> Self {
+		let (name, isNickname) = monster.get_name();
+		return LogName {
+			name,
+			isNickname,
+			isPlayer,
+		}
+	}
+
+	/// Returns the localized name, marking monsters on the enemy side.
+	pub fn to_text(&self, localization: &HashMap<String, String>) -> String {
+		let name = if self.isNickname { self.name.to_string() } else { localize(localization, &self.name) };
+		if self.isPlayer { return name; }
+		return localize(localization, "battle_log_enemy").replace("{MONSTER}", &name);
+	}
+
+}
+
+impl BattleEvent {
+
+	/// Creates the localized battle log line for the event.
+	pub fn to_text(&self, localization: &HashMap<String, String>) -> String {
+		match self {
+			BattleEvent::Start { seed } => return localize(localization, "battle_log_start").replace("{SEED}", &seed.to_string()),
+			BattleEvent::SentOut { monster } => return localize(localization, "battle_log_sent_out").replace("{MONSTER}", &monster.to_text(localization)),
+			BattleEvent::Attack { user, attack } => {
+				return localize(localization, "battle_log_attack")
+					.replace("{USER}", &user.to_text(localization))
+					.replace("{ATTACK}", &localize(localization, &attack.to_string()));
+			}
+			BattleEvent::Hit { target, damage, effectiveness, critical } => {
+				let name = target.to_text(localization);
+				let mut parts: Vec<String> = Vec::new();
+				if *damage > 0 { parts.push(localize(localization, "battle_log_hit").replace("{DAMAGE}", &damage.to_string()).replace("{TARGET}", &name)); }
+				if *critical { parts.push(localize(localization, "battle_log_critical")); }
+				if effectiveness.message().is_some() { parts.push(localize(localization, effectiveness.message().unwrap()).replace("{MONSTER_0_NAME}", &name)); }
+				return parts.join(" ");
+			}
+			BattleEvent::Missed { target } => {
+				if target.is_none() { return localize(localization, "battle_log_missed_all"); }
+				return localize(localization, "battle_log_missed").replace("{TARGET}", &target.as_ref().unwrap().to_text(localization));
+			}
+			BattleEvent::Condition { target, condition } => {
+				return localize(localization, &("battle_log_condition_".to_string() + &condition.to_string())).replace("{TARGET}", &target.to_text(localization));
+			}
+			BattleEvent::StatChange { target, stat, amount } => {
+				let key = if *amount > 0 { "battle_log_stat_up" } else { "battle_log_stat_down" };
+				return localize(localization, key)
+					.replace("{TARGET}", &target.to_text(localization))
+					.replace("{STAT}", &localize(localization, &("stage_".to_string() + &stat.to_string())))
+					.replace("{AMOUNT}", &amount.abs().to_string());
+			}
+			BattleEvent::ConditionDamage { target, damage } => {
+				return localize(localization, "battle_log_condition_damage").replace("{TARGET}", &target.to_text(localization)).replace("{DAMAGE}", &damage.to_string());
+			}
+			BattleEvent::Ability { user, ability } => {
+				return localize(localization, "battle_log_ability")
+					.replace("{USER}", &user.to_text(localization))
+					.replace("{ABILITY}", &localize(localization, &ability.data().name));
+			}
+			BattleEvent::Fainted { target } => return localize(localization, "battle_log_fainted").replace("{TARGET}", &target.to_text(localization)),
+			BattleEvent::RoundEnd { round } => return localize(localization, "battle_log_round_end").replace("{ROUND}", &round.to_string()),
+			BattleEvent::Flee { success } => {
+				if *success { return localize(localization, "battle_log_flee"); }
+				return localize(localization, "battle_log_flee_failed");
+			}
+			BattleEvent::Capture { monster, success } => {
+				let key = if *success { "battle_log_capture" } else { "battle_log_capture_failed" };
+				return localize(localization, key).replace("{MONSTER}", &monster.to_text(localization));
+			}
+			BattleEvent::Outcome { outcome } => return localize(localization, &("battle_log_outcome_".to_string() + &outcome.to_string())),
+		}
+	}
+
+}
+
+/// Creates the localized lines for every event, in order.
+pub fn to_lines(events: &[BattleEvent], localization: &HashMap<String, String>) -> Vec<String> {
+	return events.iter().map(|e| e.to_text(localization)).filter(|l| l != "").collect();
+}
+
+/// Writes the battle log to a new text file in ``LOG_FOLDER`` and returns it's path.
+pub fn save(events: &[BattleEvent], localization: &HashMap<String, String>) -> Option<String> {
+	let _ = fs::create_dir_all(LOG_FOLDER);
+	let path = format!("{}/battle_{}.txt", LOG_FOLDER, chrono::Local::now().format("%Y%m%d_%H%M%S_%3f"));
+
+	let mut text = to_lines(events, localization).join("\n");
+	text.push('\n');
+	if fs::write(&path, text).is_err() {
+		debug::log(&format!("[ERROR] - Failed to write battle log file \"{}\".\n", path));
+		return None;
+	}
+	return Some(path);
+}
+
+/// Returns the localized text for the key, or the key itself if it's missing.
+fn localize(localization: &HashMap<String, String>, key: &str) -> String {
+	return localization.get(key).cloned().unwrap_or(key.to_string());
+}
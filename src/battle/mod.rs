@@ -6,8 +6,30 @@
 
 
 //= Imports
-use crate::{monsters::{self, MonsterSpecies, MonsterAttacks}, world::Tile, raylib::{vectors::{Vector3, Vector2}, textures::Texture, self, rectangles::Rectangle}, data, utilities::math::is_within_range, settings::Difficulty};
-use std::collections::HashMap;
+pub mod damage;
+pub mod ai;
+pub mod wild;
+pub mod rewards;
+pub mod engine;
+pub mod replay;
+pub mod arena;
+pub mod pathfinding;
+pub mod animation;
+pub mod log;
+
+use crate::{monsters::{self, MonsterSpecies, MonsterAttacks, MonsterTypes, BattleStat, attacks::{AttackPosition, AreaAnchor}}, raylib::{vectors::{Vector3, Vector2}, textures::Texture, self, rectangles::Rectangle}, data, settings::Difficulty, audio::Audio, utilities::{random::Random, debug}, events::conditionals::Condition};
+use engine::BattleAction;
+use arena::{BattleTile, Terrain};
+use std::{cell::RefCell, collections::{HashMap, VecDeque}, fmt::Display};
+
+
+//= Constants
+/// ID of the event created to give out rewards after a battle is won.
+pub const REWARD_EVENT: &str = "battle_rewards";
+/// Frames between each action when playing back a replay.
+pub const PLAYBACK_DELAY: i32 = 60;
+/// Battle object IDs in the order of their turn slots.
+pub const TURN_SLOTS: [&str;4] = ["player_1", "player_2", "enemy_1", "enemy_2"];
 
 
 //= Enumerations
@@ -25,6 +47,8 @@ pub enum BattleType {
 		hardTeam:	monsters::MonsterTeam,
 
 		arena: ArenaType,
+		/// Money given to the player for winning.
+		reward: i32,
 	},
 	/// Data for a Double Battle
 	Double{
@@ -36,6 +60,8 @@ pub enum BattleType {
 		hardTeam:	[monsters::MonsterTeam;2],
 
 		arena: ArenaType,
+		/// Money given to the player for winning.
+		reward: i32,
 	},
 	/// Data for a Wild Battle
 	Wild{
@@ -52,6 +78,15 @@ pub enum ArenaType {
 	Forest,
 	City,
 }
+impl Display for ArenaType {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ArenaType::Field	=> write!(f, "field"),
+			ArenaType::Forest	=> write!(f, "forest"),
+			ArenaType::City		=> write!(f, "city"),
+		}
+	}
+}
 impl From<&str> for ArenaType {
 	fn from(value: &str) -> Self {
 		match value {
@@ -68,7 +103,27 @@ impl From<&str> for ArenaType {
 pub enum BattleObjectType {
 	Delete,
 	PlayerMonster{num: i32, species: MonsterSpecies},
-	EnemyMonster{num: i32, species: MonsterSpecies},
+	/// ``team`` is the index into ``BattleData::enemyTeams``.
+	EnemyMonster{num: i32, team: usize, species: MonsterSpecies},
+}
+
+/// How a battle ended
+#[derive(Clone, Copy, PartialEq)]
+pub enum BattleOutcome {
+	Win,
+	Loss,
+	Fled,
+	Captured,
+}
+impl Display for BattleOutcome {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			BattleOutcome::Win		=> write!(f, "win"),
+			BattleOutcome::Loss		=> write!(f, "loss"),
+			BattleOutcome::Fled		=> write!(f, "fled"),
+			BattleOutcome::Captured	=> write!(f, "captured"),
+		}
+	}
 }
 
 /// Player Battle State
@@ -76,6 +131,8 @@ pub enum BattleObjectType {
 pub enum PlayerBattleState {
 	Movement,
 	Attack,
+	/// Choosing a team member to switch in.
+	Switch,
 }
 
 //= Structures
@@ -88,20 +145,62 @@ pub struct BattleData {
 	pub turnCur: i8,
 	pub roundTotal: i32,
 	pub turnOrder: [i8;4],
+	/// The action each turn slot chose for this round, ``None`` until it has chosen.
+	pub pendingActions: [Option<BattleAction>;4],
 
 	pub playerState: PlayerBattleState,
 	pub cursor: Vector3,
 	pub movementCursor: Vector3,
 
 	pub attackChoice: i32,
+	/// The player team position highlighted in the switch menu.
+	pub switchChoice: i32,
 
 	//* Data */
 	pub battleType: BattleType,
 	pub playerTeam: monsters::MonsterTeam,
-	pub enemyTeam:  monsters::MonsterTeam,
+	/// The enemy teams, the second is only used in double battles.
+	pub enemyTeams: [monsters::MonsterTeam;2],
+	/// Difficulty the enemy teams and AI are picked for, taken from the settings by ``start_battle``.
+	pub difficulty: Difficulty,
 
-	pub tiles: HashMap<[i32;3], Tile>,
+	pub tiles: HashMap<[i32;3], BattleTile>,
 	pub objects: HashMap<String, BattleObject>,
+
+	/// All attack results from the current battle, in order.
+	pub results: Vec<damage::AttackResult>,
+	/// The seed the battle was started with, plays out the same given the same actions.
+	pub seed: u64,
+	/// Random number generator used by the battle rules.
+	pub rng: Random,
+	/// Random number generator used by the AI, kept apart so replays don't need to run it.
+	/// <br>In a cell so the AI can roll it while reading the battle.
+	pub aiRng: RefCell<Random>,
+
+	/// Set when the battle is over and waiting for ``end_battle``.
+	pub outcome: Option<BattleOutcome>,
+	/// Events started when the battle is won or lost.
+	pub winEvent: Option<String>,
+	pub lossEvent: Option<String>,
+	/// The wild monster caught this battle.
+	pub captured: Option<monsters::Monster>,
+	pub fleeAttempts: i32,
+	/// Player team positions that have been out on the field this battle.
+	pub participants: [bool;4],
+	/// Stops the battle from keeping a log, for simulations.
+	pub silent: bool,
+	/// Everything that happened this battle in order, shown as the battle log.
+	pub events: Vec<log::BattleEvent>,
+	/// How many lines the battle log is scrolled back from the newest.
+	pub logScroll: i32,
+	/// The replay being recorded, if recording is turned on.
+	pub recording: Option<replay::Replay>,
+	/// The replay being played back instead of taking input.
+	pub playback: Option<replay::Playback>,
+	/// Whether attacks are animated, with the turn ending once ``animations`` is empty.
+	pub animated: bool,
+	/// Steps of the attack being animated, the first is playing.
+	pub animations: VecDeque<animation::AnimationStep>,
 }
 
 ///
@@ -141,27 +240,75 @@ impl BattleData {
 			turnCur: 0,
 			roundTotal: 0,
 			turnOrder:	[-1,-1,-1,-1],
+			pendingActions: [None, None, None, None],
 
 			playerState: PlayerBattleState::Movement,
 			cursor: Vector3{x:4.0,y:0.0,z:4.0},
 			movementCursor: Vector3 {x:-1.0,y:-1.0,z:-1.0},
 
 			attackChoice: 0,
+			switchChoice: 0,
 
     		battleType:	BattleType::Empty,
 			playerTeam: monsters::MonsterTeam::new(),
-			enemyTeam: monsters::MonsterTeam::new(),
+			enemyTeams: [monsters::MonsterTeam::new(), monsters::MonsterTeam::new()],
+			difficulty: Difficulty::Medium,
 
 			tiles: HashMap::new(),
 			objects: HashMap::new(),
+
+			results: Vec::new(),
+			seed: 0,
+			rng: Random::from_time(),
+			aiRng: RefCell::new(Random::from_time()),
+
+			outcome: None,
+			winEvent: None,
+			lossEvent: None,
+			captured: None,
+			fleeAttempts: 0,
+			participants: [false;4],
+			silent: false,
+			events: Vec::new(),
+			logScroll: 0,
+			recording: None,
+			playback: None,
+			animated: true,
+			animations: VecDeque::new(),
 		}
 	}
 
 	/// Start battle
 	/// <br>If ``BattleType::Empty input``, it clears the structure.
 	pub fn start_battle(&mut self, battle: BattleType, playerTeam: &monsters::MonsterTeam) {
+		self.difficulty = data::get_difficulty();
+		let seed = Random::from_time().seed;
+		self.start_seeded_battle(battle, playerTeam, seed);
+	}
+
+	/// Start battle with the input seed for it's random number generator, so it plays out the same given the same actions.
+	pub fn start_seeded_battle(&mut self, battle: BattleType, playerTeam: &monsters::MonsterTeam, seed: u64) {
+		self.results = Vec::new();
+		self.seed = seed;
+		self.rng = Random::new(seed);
+		self.aiRng = RefCell::new(Random::new(!seed));
+		self.recording = None;
+		self.playback = None;
+		self.animations = VecDeque::new();
+		self.events = Vec::new();
+		self.logScroll = 0;
+		self.turnCur = 0;
+		self.roundTotal = 0;
+		self.pendingActions = [None, None, None, None];
+		self.outcome = None;
+		self.winEvent = None;
+		self.lossEvent = None;
+		self.captured = None;
+		self.fleeAttempts = 0;
+		self.participants = [false;4];
+
 		match battle {
-			BattleType::Single { arena, trainerName, easyTeam, mediumTeam, hardTeam } => {
+			BattleType::Single { arena, trainerName, easyTeam, mediumTeam, hardTeam, reward } => {
 				self.started = true;
 				self.battleType = BattleType::Single{
 					trainerName: trainerName.clone(),
@@ -169,316 +316,533 @@ impl BattleData {
 					mediumTeam: mediumTeam.clone(),
 					hardTeam: hardTeam.clone(),
 					arena: arena.clone(),
+					reward,
 				};
 				self.playerTeam = playerTeam.clone();
-				match data::get_difficulty() {
-					Difficulty::Easy	=> { self.enemyTeam = easyTeam; }
-					Difficulty::Medium	=> { self.enemyTeam = mediumTeam; }
-					Difficulty::Hard	=> { self.enemyTeam = hardTeam; }
+				match self.difficulty {
+					Difficulty::Easy	=> { self.enemyTeams = [easyTeam, monsters::MonsterTeam::new()]; }
+					Difficulty::Medium	=> { self.enemyTeams = [mediumTeam, monsters::MonsterTeam::new()]; }
+					Difficulty::Hard	=> { self.enemyTeams = [hardTeam, monsters::MonsterTeam::new()]; }
 				}
 
-				self.turnOrder = calc_turn_order([playerTeam.0[0].clone(), None, self.enemyTeam.0[0].clone(), None]);
-				print!("[{},{},{},{}]\n",self.turnOrder[0],self.turnOrder[1],self.turnOrder[2],self.turnOrder[3]);
-
 				self.objects = HashMap::new();
-				self.objects.insert(
-					"player_1".to_string(), 
-					BattleObject::new(
-						BattleObjectType::PlayerMonster{num: 0, species: self.playerTeam.0[0].as_ref().unwrap().species.clone()},
-						Vector3{x:4.0,y:0.0,z:4.0},
-					),
-				);
-				self.objects.insert(
-					"enemy_1".to_string(), 
-					BattleObject::new(
-						BattleObjectType::EnemyMonster{num: 0, species: self.enemyTeam.0[0].as_ref().unwrap().species.clone()},
-						Vector3{x:11.0,y:0.0,z:4.0},
-					),
-				);
+				self.place_player("player_1", 0, Vector3{x:4.0,y:0.0,z:4.0});
+				self.place_enemy("enemy_1", 0, 0, Vector3{x:11.0,y:0.0,z:4.0});
+
+				self.recalc_turn_order();
 
 				self.tiles = BattleData::create_arena(arena);
 			}
-			BattleType::Double { arena, .. } => {
+			BattleType::Double { arena, trainerName, singleTrainer, easyTeam, mediumTeam, hardTeam, reward } => {
 				self.started = true;
-				self.battleType = battle;
+				self.battleType = BattleType::Double{
+					trainerName: trainerName.clone(),
+					singleTrainer,
+					easyTeam: easyTeam.clone(),
+					mediumTeam: mediumTeam.clone(),
+					hardTeam: hardTeam.clone(),
+					arena: arena.clone(),
+					reward,
+				};
+				self.playerTeam = playerTeam.clone();
+				match self.difficulty {
+					Difficulty::Easy	=> { self.enemyTeams = easyTeam; }
+					Difficulty::Medium	=> { self.enemyTeams = mediumTeam; }
+					Difficulty::Hard	=> { self.enemyTeams = hardTeam; }
+				}
+
+				//* Each side gets two monsters, one from each enemy team */
+				self.objects = HashMap::new();
+				self.place_player("player_1", 0, Vector3{x:4.0,y:0.0,z:2.0});
+				self.place_player("player_2", 1, Vector3{x:4.0,y:0.0,z:5.0});
+				self.place_enemy("enemy_1", 0, 0, Vector3{x:11.0,y:0.0,z:2.0});
+				self.place_enemy("enemy_2", 1, 0, Vector3{x:11.0,y:0.0,z:5.0});
+
+				self.recalc_turn_order();
 
 				self.tiles = BattleData::create_arena(arena);
 			}
-			BattleType::Wild { arena, .. } => {
+			BattleType::Wild { arena, monster } => {
 				self.started = true;
-				self.battleType = battle;
+				self.battleType = BattleType::Wild{
+					monster: monster.clone(),
+					arena: arena.clone(),
+				};
+				self.playerTeam = playerTeam.clone();
+				self.enemyTeams = [monsters::MonsterTeam::new(), monsters::MonsterTeam::new()];
+				self.enemyTeams[0].add_member(monster);
+
+				self.objects = HashMap::new();
+				self.place_player("player_1", 0, Vector3{x:4.0,y:0.0,z:4.0});
+				self.place_enemy("enemy_1", 0, 0, Vector3{x:11.0,y:0.0,z:4.0});
+
+				self.recalc_turn_order();
 
 				self.tiles = BattleData::create_arena(arena);
 			}
 			BattleType::Empty => {
 				self.started = false;
 				self.battleType = BattleType::Empty;
+
+				//* Stat stages only last for the battle */
+				let [enemyFirst, enemySecond] = &mut self.enemyTeams;
+				for monster in self.playerTeam.0.iter_mut().chain(enemyFirst.0.iter_mut()).chain(enemySecond.0.iter_mut()) {
+					if monster.is_some() { monster.as_mut().unwrap().reset_stat_stages(); }
+				}
 			}
 		}
-	}
 
-	pub fn create_arena(arena: ArenaType) -> HashMap<[i32;3], Tile> {
-		let mut result: HashMap<[i32;3], Tile> = HashMap::new();
-
-		match arena {
-			ArenaType::Field => {
-				//let grass = Tile::create("grass_1", false, false);
-				let battle1 = Tile::create("battle_1", false, false);
-				let battle2 = Tile::create("battle_2", false, false);
-				let battle3 = Tile::create("battle_3", false, false);
-
-				// TEMP
-				//for z in -13..10 {
-				//	for x in -16..32 {
-				for z in 0..8 {
-					for x in 0..16 {
-						if x == 7 { result.insert([x,0,z], battle2.clone()); }
-						else if x == 8 { result.insert([x,0,z], battle3.clone()); }
-						else { result.insert([x,0,z], battle1.clone()); }
-					}
-				}
+		if self.started {
+			self.log(log::BattleEvent::Start{ seed });
+			for objectID in TURN_SLOTS.iter() { self.trigger_on_enter(objectID); }
+		}
 
-				return result;
-			}
-			ArenaType::Forest => {
-				let grass = Tile::create("grass_1", false, false);
+		//* Record the battle as it starts */
+		if self.started && data::get_record_replays() {
+			self.recording = Some(replay::Replay::new(seed, &self.battleType, &self.playerTeam, &self.enemyTeams));
+		}
+	}
 
-				// TEMP
-				for z in 0..8 {
-					for x in 0..16 {
-						result.insert([x,0,z], grass.clone());
-					}
-				}
+	/// Starts playing back a recorded battle.
+	pub fn start_replay(&mut self, replay: replay::Replay) {
+		self.start_seeded_battle(replay.battle.clone(), &replay.playerTeam, replay.seed);
+		self.playback = Some(replay::Playback {
+			replay,
+			next: 0,
+			timer: PLAYBACK_DELAY,
+		});
+	}
 
-				return result;
-			}
-			ArenaType::City => {
-				let grass = Tile::create("grass_1", false, false);
+	/// Places a battle object for the player's monster in the input team slot, if there is one.
+	fn place_player(&mut self, objectID: &str, num: i32, position: Vector3) {
+		let monster = self.playerTeam.0[num as usize].as_ref();
+		if monster.is_none() { return; }
+		self.participants[num as usize] = true;
+
+		self.objects.insert(
+			objectID.to_string(),
+			BattleObject::new(
+				BattleObjectType::PlayerMonster{num, species: monster.unwrap().species.clone()},
+				position,
+			),
+		);
+	}
 
-				// TEMP
-				for z in 0..8 {
-					for x in 0..16 {
-						result.insert([x,0,z], grass.clone());
-					}
-				}
+	/// Places a battle object for the enemy's monster in the input team and slot, if there is one.
+	fn place_enemy(&mut self, objectID: &str, team: usize, num: i32, position: Vector3) {
+		let monster = self.enemyTeams[team].0[num as usize].as_ref();
+		if monster.is_none() { return; }
+
+		self.objects.insert(
+			objectID.to_string(),
+			BattleObject::new(
+				BattleObjectType::EnemyMonster{num, team, species: monster.unwrap().species.clone()},
+				position,
+			),
+		);
+	}
 
-				return result;
-			}
+	/// Creates the tiles of the arena from it's layout.
+	/// <br>Falls back to a flat grid if the layout doesn't exist.
+	pub fn create_arena(arena: ArenaType) -> HashMap<[i32;3], BattleTile> {
+		let layout = data::ARENAS.get(&arena.to_string());
+		if layout.is_some() { return layout.unwrap().clone(); }
+
+		debug::log(&format!("[ERROR] - Arena \"{}\" doesn't exist.\n", arena));
+		let mut result: HashMap<[i32;3], BattleTile> = HashMap::new();
+		for z in 0..8 {
+			for x in 0..16 { result.insert([x,0,z], BattleTile::create("battle_1")); }
 		}
+		return result;
 	}
 
 	/// Updates battle state
-	pub fn update(&mut self) {
+	pub fn update(&mut self, audio: &mut Audio) {
 		//if data::key_pressed("swap_modes") {
 		//	if self.playerState == PlayerBattleState::Movement { self.playerState = PlayerBattleState::Attack }
 		//	else if self.playerState == PlayerBattleState::Attack { self.playerState = PlayerBattleState::Movement }
 		//}
-		match self.turnOrder[self.turnCur as usize] {
+		//* The log can be scrolled back at any point */
+		let scroll = raylib::mouse_wheel_move();
+		if scroll != 0.0 {
+			let lines = self.events.len().saturating_sub(log::VISIBLE_LINES) as i32;
+			self.logScroll = (self.logScroll + scroll.signum() as i32).clamp(0, lines);
+		}
+
+		//* Nothing happens until the current attack has played out */
+		if !self.animations.is_empty() {
+			self.update_animations(audio);
+			return;
+		}
+		if self.outcome.is_some() { return; }
+
+		//* Replays play out on their own */
+		if self.playback.is_some() {
+			self.update_playback();
+			return;
+		}
+
+		//* Fainted monsters are replaced before the turn continues */
+		if self.pending_replacement().is_some() {
+			self.switch_input(audio, true);
+			return;
+		}
+
+		//* Every monster chooses it's action before the round is played out */
+		match self.choosing_slot().map(|s| s as i8).unwrap_or(-1) {
 			0 => { // Player mon 1
-				match self.playerState {
-					PlayerBattleState::Movement => {
-						if data::key_pressed("up")		{ self.cursor = self.cursor - Vector3{x:0.0,y:0.0,z:1.0}; }
-						if data::key_pressed("down")	{ self.cursor = self.cursor + Vector3{x:0.0,y:0.0,z:1.0}; }
-						if data::key_pressed("left")	{ self.cursor = self.cursor - Vector3{x:1.0,y:0.0,z:0.0}; }
-						if data::key_pressed("right")	{ self.cursor = self.cursor + Vector3{x:1.0,y:0.0,z:0.0}; }
-
-						if self.cursor.x < 0.0 { self.cursor.x = 0.0; }
-						if self.cursor.x > 7.0 { self.cursor.x = 7.0; }
-						if self.cursor.z < 0.0 { self.cursor.z = 0.0; }
-						if self.cursor.z > 7.0 { self.cursor.z = 7.0; }
-
-						if data::key_pressed("confirm")	{
-							//* Check if cursor is within movement range */
-							if is_within_range(self.objects["player_1"].position, self.cursor, self.playerTeam.0[0].as_ref().unwrap().get_move_distance()) {
-								self.movementCursor = self.cursor;
-								self.playerState = PlayerBattleState::Attack;
-							}
-						}
+				self.player_turn(audio, TURN_SLOTS[0]);
+			}
+			1 => { // Player mon 2
+				self.player_turn(audio, TURN_SLOTS[1]);
+			}
+			2 => { // Enemy mon 1
+				self.enemy_turn();
+			}
+			3 => { // Enemy mon 2
+				self.enemy_turn();
+			}
+			_ => {} // Null
+		}
+	}
+
+	/// Performs the next action of the replay being played back once the delay has passed.
+	/// <br>The battle ends as fled if the replay runs out or goes out of sync.
+	fn update_playback(&mut self) {
+		let playback = self.playback.as_mut().unwrap();
+		playback.timer -= 1;
+		if playback.timer > 0 { return; }
+		playback.timer = PLAYBACK_DELAY;
+
+		if playback.next >= playback.replay.actions.len() {
+			debug::log("[ERROR] - Replay ran out of actions before the battle ended.\n");
+			self.outcome = Some(BattleOutcome::Fled);
+			return;
+		}
+		let action = playback.replay.actions[playback.next].clone();
+		playback.next += 1;
+
+		if !self.apply_action(&action) {
+			debug::log("[ERROR] - Replay action couldn't be performed, the replay is out of sync.\n");
+			self.outcome = Some(BattleOutcome::Fled);
+		}
+	}
+
+	/// Handles input for choosing the player monster's movement and attack for the round.
+	fn player_turn(&mut self, audio: &mut Audio, objectID: &str) {
+		let monster = self.get_monster(objectID);
+		if monster.is_none() { return; }
+		let monster = monster.unwrap().clone();
+
+		match self.playerState {
+			PlayerBattleState::Movement => {
+				if data::key_pressed("up")		{ self.cursor = self.cursor - Vector3{x:0.0,y:0.0,z:1.0}; }
+				if data::key_pressed("down")	{ self.cursor = self.cursor + Vector3{x:0.0,y:0.0,z:1.0}; }
+				if data::key_pressed("left")	{ self.cursor = self.cursor - Vector3{x:1.0,y:0.0,z:0.0}; }
+				if data::key_pressed("right")	{ self.cursor = self.cursor + Vector3{x:1.0,y:0.0,z:0.0}; }
+
+				if self.cursor.x < 0.0 { self.cursor.x = 0.0; }
+				if self.cursor.x > 7.0 { self.cursor.x = 7.0; }
+				if self.cursor.z < 0.0 { self.cursor.z = 0.0; }
+				if self.cursor.z > 7.0 { self.cursor.z = 7.0; }
+
+				//* Wild battle actions */
+				if let BattleType::Wild { .. } = self.battleType {
+					if data::key_pressed("flee") {
+						self.apply_action(&BattleAction::Flee);
+						return;
 					}
-					PlayerBattleState::Attack => {
-						// TODO Check for empty attack slots? or i can just have it not do anything if used on them.
-						if data::key_pressed("attack_1") && self.playerTeam.0[0].as_ref().unwrap().attacks[0] != MonsterAttacks::None { self.attackChoice = 0; }
-						if data::key_pressed("attack_2") && self.playerTeam.0[0].as_ref().unwrap().attacks[1] != MonsterAttacks::None { self.attackChoice = 1; }
-						if data::key_pressed("attack_3") && self.playerTeam.0[0].as_ref().unwrap().attacks[2] != MonsterAttacks::None { self.attackChoice = 2; }
-						if data::key_pressed("attack_4") && self.playerTeam.0[0].as_ref().unwrap().attacks[3] != MonsterAttacks::None { self.attackChoice = 3; }
-						
-						if data::key_pressed("up")		{ self.cursor = self.cursor - Vector3{x:0.0,y:0.0,z:1.0}; }
-						if data::key_pressed("down")	{ self.cursor = self.cursor + Vector3{x:0.0,y:0.0,z:1.0}; }
-						if data::key_pressed("left")	{ self.cursor = self.cursor - Vector3{x:1.0,y:0.0,z:0.0}; }
-						if data::key_pressed("right")	{ self.cursor = self.cursor + Vector3{x:1.0,y:0.0,z:0.0}; }
-
-						if self.cursor.x < 8.0 { self.cursor.x = 8.0; }
-						if self.cursor.x > 16.0 { self.cursor.x = 16.0; }
-						if self.cursor.z < 0.0 { self.cursor.z = 0.0; }
-						if self.cursor.z > 7.0 { self.cursor.z = 7.0; }
-
-						if data::key_pressed("rotate_left")		{ self.attackChoice = self.attackChoice - 1; }
-						if data::key_pressed("rotate_right")	{ self.attackChoice = self.attackChoice + 1; }
-
-						if self.attackChoice < 0 { self.attackChoice = 0; }
-						if self.attackChoice > 3 { self.attackChoice = 3; }
-
-						if data::key_pressed("confirm") {
-							//* Do attack */
-							// TODO Check if the attack is actually usable. If not don't go to next turn. Maybe play sound?
-							let currentAttack = self.playerTeam.0[0].clone().unwrap().attacks[self.attackChoice as usize].clone();
-							match currentAttack {
-								MonsterAttacks::Tackle => {
-									if self.objects["player_1"].position.x > 3.0 {
-										//TODO Attacks
-										print!("Player turn ends.\n");
-										self.next_turn();
-									}
-								}
-								MonsterAttacks::Scratch => {}
-								MonsterAttacks::Growl => {}
-								MonsterAttacks::Leer => {}
-								MonsterAttacks::Leafage => {}
-								MonsterAttacks::Ember => {}
-								MonsterAttacks::Aquajet => {}
-								_ => {}
-							}
-						}
+					if data::key_pressed("capture") {
+						audio.play_sound("button".to_string());
+						self.apply_action(&BattleAction::Capture);
+						return;
+					}
+				}
 
-						if data::key_pressed("cancel")	{
-							self.movementCursor = Vector3{x:-1.0,y:-1.0,z:-1.0};
-							self.playerState = PlayerBattleState::Movement;
-						}
+				if data::key_pressed("switch") {
+					let choice = (0..4).find(|n| self.can_switch_to(*n));
+					if choice.is_some() {
+						self.switchChoice = choice.unwrap();
+						self.playerState = PlayerBattleState::Switch;
+						return;
 					}
 				}
+
 				if data::key_pressed("confirm")	{
-					//print!("Player turn ends.\n");
-					//self.next_turn();
+					//* Check if cursor is within movement range and not on an ally */
+					if self.can_move_to(objectID, self.cursor) {
+						self.movementCursor = self.cursor;
+						self.playerState = PlayerBattleState::Attack;
+					}
 				}
 			}
-			1 => {} // Player mon 2
-			2 => { // Enemy mon 1
-				print!("Enemy turn ends.\n");
-				self.next_turn();
+			PlayerBattleState::Attack => {
+				// TODO Check for empty attack slots? or i can just have it not do anything if used on them.
+				if data::key_pressed("attack_1") && monster.attacks[0] != MonsterAttacks::None { self.attackChoice = 0; }
+				if data::key_pressed("attack_2") && monster.attacks[1] != MonsterAttacks::None { self.attackChoice = 1; }
+				if data::key_pressed("attack_3") && monster.attacks[2] != MonsterAttacks::None { self.attackChoice = 2; }
+				if data::key_pressed("attack_4") && monster.attacks[3] != MonsterAttacks::None { self.attackChoice = 3; }
+				
+				if data::key_pressed("up")		{ self.cursor = self.cursor - Vector3{x:0.0,y:0.0,z:1.0}; }
+				if data::key_pressed("down")	{ self.cursor = self.cursor + Vector3{x:0.0,y:0.0,z:1.0}; }
+				if data::key_pressed("left")	{ self.cursor = self.cursor - Vector3{x:1.0,y:0.0,z:0.0}; }
+				if data::key_pressed("right")	{ self.cursor = self.cursor + Vector3{x:1.0,y:0.0,z:0.0}; }
+
+				if self.cursor.x < 8.0 { self.cursor.x = 8.0; }
+				if self.cursor.x > 16.0 { self.cursor.x = 16.0; }
+				if self.cursor.z < 0.0 { self.cursor.z = 0.0; }
+				if self.cursor.z > 7.0 { self.cursor.z = 7.0; }
+
+				if data::key_pressed("rotate_left")		{ self.attackChoice = self.attackChoice - 1; }
+				if data::key_pressed("rotate_right")	{ self.attackChoice = self.attackChoice + 1; }
+
+				if self.attackChoice < 0 { self.attackChoice = 0; }
+				if self.attackChoice > 3 { self.attackChoice = 3; }
+
+				if data::key_pressed("confirm") {
+					//* Do attack */
+					let currentAttack = monster.attacks[self.attackChoice as usize].clone();
+					if BattleData::can_use_attack(&currentAttack, self.movementCursor, true) {
+						self.apply_action(&BattleAction::Attack{position: self.movementCursor, attack: currentAttack, cursor: self.cursor});
+					}
+				}
+
+				if data::key_pressed("cancel")	{
+					self.movementCursor = Vector3{x:-1.0,y:-1.0,z:-1.0};
+					self.playerState = PlayerBattleState::Movement;
+				}
 			}
-			3 => { // Enemy mon 2
-				print!("Enemy turn ends.\n");
-				self.next_turn();
+			PlayerBattleState::Switch => {
+				self.switch_input(audio, false);
 			}
-			_ => {} // Null
 		}
 	}
 
-	pub fn next_turn(&mut self) {
-		self.playerState = PlayerBattleState::Movement;
-		let (str, _) = self.get_current_monster();
-		if str == "player_1" || str == "player_2" {
-			self.objects.get_mut(&str).unwrap().position = self.movementCursor;
-			self.movementCursor = Vector3{x:-1.0,y:-1.0,z:-1.0};
+	/// Handles input for choosing a team member to send out in place of the current or fainted monster.
+	/// <br>Forced replacements after a faint can't be cancelled and don't use up the turn.
+	fn switch_input(&mut self, audio: &mut Audio, forced: bool) {
+		if data::key_pressed("up")		{ self.switchChoice -= 1; }
+		if data::key_pressed("down")	{ self.switchChoice += 1; }
+
+		if self.switchChoice < 0 { self.switchChoice = 0; }
+		if self.switchChoice > 3 { self.switchChoice = 3; }
+
+		if data::key_pressed("confirm") && self.can_switch_to(self.switchChoice) {
+			audio.play_sound("button".to_string());
+			self.playerState = PlayerBattleState::Movement;
+
+			self.apply_action(&BattleAction::Switch{num: self.switchChoice});
+			return;
 		}
 
-		self.turnCur += 1;
-		//* If turn is invalid, reset round */
-		if self.turnCur >= 4 || self.turnOrder[self.turnCur as usize] == -1 {
-			print!("Round ends.\n");
-			self.roundTotal += 1;
-			self.turnCur = 0;
+		if data::key_pressed("cancel") && !forced {
+			self.playerState = PlayerBattleState::Movement;
 		}
 	}
 
-	pub fn get_current_monster(&self) -> (String, i8) {
-		print!("{}\n",self.turnOrder[self.turnCur as usize]);
-		let mut current = self.turnOrder[self.turnCur as usize] + 1;
-		let mon: String;
-		if current > 2 {
-			mon = "enemy_".to_string();
-			current -= 2;
-		} else { mon = "player_".to_string(); }
+	/// Lets the AI choose an action for the enemy monster whose choice it is.
+	fn enemy_turn(&mut self) {
+		let action = ai::choose_action(self, self.difficulty, &mut self.aiRng.borrow_mut());
+
+		if !self.apply_action(&action) { self.apply_action(&BattleAction::Pass); }
+	}
 
-		return (mon + &current.to_string(), current);
+	/// Converts a world position into a column and row local to the input side.
+	/// <br>Columns count from ``0`` at the side's back edge, so ``8`` and up are on the opposing half.
+	pub fn local_position(position: [i32;3], isPlayer: bool) -> [i32;2] {
+		if isPlayer { return [position[0], position[2]]; }
+		else { return [15 - position[0], position[2]]; }
 	}
 
+	/// Checks whether the attack can be used from the input position.
+	pub fn can_use_attack(attack: &MonsterAttacks, position: Vector3, isPlayer: bool) -> bool {
+		if *attack == MonsterAttacks::None { return false; }
+
+		let user = BattleData::local_position(position.into(), isPlayer);
+		return attack.data().usable_from(user[0]);
+	}
+
+	/// Checks whether the tile is within the area of an attack used from the input position.
+	pub fn attack_covers(attack: &MonsterAttacks, position: Vector3, cursor: Vector3, tile: [i32;3], isPlayer: bool) -> bool {
+		let user = BattleData::local_position(position.into(), isPlayer);
+		let mut cursor = BattleData::local_position(cursor.into(), isPlayer);
+		let mut tile = BattleData::local_position(tile, isPlayer);
+		cursor[0] -= 8;
+		tile[0] -= 8;
+
+		return attack.data().get_area(user[0]).covers(user, cursor, tile);
+	}
+
+	/// Checks whether the input battle object is one of the player's monsters.
+	pub fn is_player(&self, objectID: &str) -> bool {
+		match self.objects.get(objectID).map(|o| &o.objType) {
+			Some(BattleObjectType::PlayerMonster { .. }) => return true,
+			_ => return false,
+		}
+	}
+
+	/// Returns the damage multiplier the terrain under the input position gives an attack.
+	pub fn terrain_modifier(&self, attack: &MonsterAttacks, position: Vector3) -> f32 {
+		let key: [i32;3] = position.into();
+		let tile = self.tiles.get(&key);
+		if tile.is_some() && tile.unwrap().terrain == Terrain::TallGrass && attack.data().attackType == MonsterTypes::Grass { return arena::TALL_GRASS_BOOST; }
+		return 1.0;
+	}
+
+	/// Returns the monster represented by the input battle object.
+	pub fn get_monster(&self, objectID: &str) -> Option<&monsters::Monster> {
+		if !self.objects.contains_key(objectID) { return None; }
+
+		match self.objects[objectID].objType {
+			BattleObjectType::PlayerMonster { num, .. }	=> return self.playerTeam.0[num as usize].as_ref(),
+			BattleObjectType::EnemyMonster { num, team, .. }	=> return self.enemyTeams[team].0[num as usize].as_ref(),
+			BattleObjectType::Delete => return None,
+		}
+	}
+
+	/// Returns the monster represented by the input battle object as mutable.
+	pub fn get_monster_mut(&mut self, objectID: &str) -> Option<&mut monsters::Monster> {
+		return self.get_monster_and_rng(objectID).0;
+	}
+
+	/// Returns the monster represented by the input battle object as mutable, along with the battle's random number generator.
+	/// <br>Lets rolls be made against the monster without copying the generator.
+	pub fn get_monster_and_rng(&mut self, objectID: &str) -> (Option<&mut monsters::Monster>, &mut Random) {
+		let monster = match self.objects.get(objectID).map(|o| &o.objType) {
+			Some(BattleObjectType::PlayerMonster { num, .. })	=> self.playerTeam.0[*num as usize].as_mut(),
+			Some(BattleObjectType::EnemyMonster { num, team, .. })	=> self.enemyTeams[*team].0[*num as usize].as_mut(),
+			_ => None,
+		};
+		return (monster, &mut self.rng);
+	}
+
+
+
+}
+
+/// Ends the current battle, giving the player any monster they captured.
+/// <br>The outcome is stored in the ``battle_outcome`` event variable, and the win or loss event is started if there is one.
+/// <br>Returns whether an event was started, replacing the current one.
+pub fn end_battle(gamestate: &mut data::Gamestate) -> bool {
+	if data::get_save_battle_logs() {
+		let path = log::save(&gamestate.battleData.events, &gamestate.localization);
+		if path.is_some() { debug::log(&format!("[INFO] - Battle log saved to \"{}\".\n", path.unwrap())); }
+	}
+
+	//* Replays don't change anything outside of the battle */
+	if gamestate.battleData.playback.is_some() {
+		gamestate.player.canMove = true;
+		gamestate.battleData.start_battle(BattleType::Empty, &gamestate.player.monsters);
+		return false;
+	}
+
+	let recording = gamestate.battleData.recording.take();
+	if recording.is_some() {
+		let path = recording.unwrap().save();
+		if path.is_some() { debug::log(&format!("[INFO] - Replay saved to \"{}\".\n", path.unwrap())); }
+	}
+
+	let captured = gamestate.battleData.captured.take();
+	if captured.is_some() { gamestate.player.give_monster(captured.unwrap()); }
+
+	let outcome = gamestate.battleData.outcome;
+	let mut event = match outcome {
+		Some(BattleOutcome::Win)	=> gamestate.battleData.winEvent.clone(),
+		Some(BattleOutcome::Loss)	=> gamestate.battleData.lossEvent.clone(),
+		_							=> None,
+	};
+
+	//* Rewards are given out through an event before continuing to the win event */
+	if outcome == Some(BattleOutcome::Win) {
+		//* Effort values are given straight away, since nothing is shown for them */
+		let effort = rewards::distribute_effort(&gamestate.battleData);
+		for i in 0..4 {
+			let monster = gamestate.player.monsters.0[i].as_mut();
+			if monster.is_none() || effort[i] == [0;6] { continue; }
+			let monster = monster.unwrap();
+			monster.give_effort(effort[i]);
+			monster.calculate_stats();
+		}
+
+		let experience = rewards::distribute_experience(&gamestate.battleData);
+		let money = match gamestate.battleData.battleType {
+			BattleType::Single { reward, .. } | BattleType::Double { reward, .. } => reward,
+			_ => 0,
+		};
+		gamestate.player.money += money;
+		gamestate.eventHandler.textVariables.insert("REWARD_MONEY".to_string(), money.to_string());
+
+		gamestate.worldData.eventList.insert(REWARD_EVENT.to_string(), rewards::create_reward_event(experience, money, event));
+		event = Some(REWARD_EVENT.to_string());
+	}
+
+	gamestate.player.canMove = true;
+	gamestate.battleData.start_battle(BattleType::Empty, &gamestate.player.monsters);
+
+	if outcome.is_some() {
+		gamestate.eventHandler.eventVariables.insert("battle_outcome".to_string(), Condition::String(outcome.unwrap().to_string()));
+	}
+	if event.is_some() {
+		gamestate.eventHandler.currentEvent = event.unwrap();
+		gamestate.eventHandler.currentChain = 0;
+		gamestate.eventHandler.internal = 0;
+		return true;
+	}
+	return false;
 }
 
 /// Draws battle to screen
 pub fn draw(gamestate: &mut data::Gamestate) {
+	//* Movement range of the player's monster */
+	let mut reachable: HashMap<[i32;3], i32> = HashMap::new();
+	let chosenMon = gamestate.battleData.choosing_slot().map(|s| s as i8).unwrap_or(-1);
+	if gamestate.battleData.playerState == PlayerBattleState::Movement && (chosenMon == 0 || chosenMon == 1) {
+		reachable = pathfinding::reachable_tiles(&gamestate.battleData, TURN_SLOTS[chosenMon as usize]);
+	}
+
 	for z in -13..10 {
 		for x in -16..32 {
 			//* Tiles */
 			if gamestate.battleData.tiles.contains_key(&[x,0,z]) {
 				let tile = gamestate.battleData.tiles[&[x,0,z]].clone();
-				let mut color: raylib_ffi::Color = raylib_ffi::colors::WHITE;
+				let mut color: raylib_ffi::Color = match tile.terrain {
+					Terrain::TallGrass	=> raylib_ffi::Color{r:170,g:230,b:170,a:255},
+					_					=> raylib_ffi::colors::WHITE,
+				};
 
-				let chosenMon = gamestate.battleData.turnOrder[gamestate.battleData.turnCur as usize];
-				
 				match gamestate.battleData.playerState {
 					PlayerBattleState::Movement => {
 						if x < 8 && z < 8 {
-							// TODO Double battle
-							if chosenMon == 0 {
-								let objPosition = gamestate.battleData.objects["player_1"].position;
-								if is_within_range(objPosition, Vector3::from([x,0,z]), gamestate.battleData.playerTeam.0[0].as_ref().unwrap().get_move_distance()) {
-									color = raylib_ffi::colors::ORANGE;
-								}
-							}
+							if reachable.contains_key(&[x,0,z]) { color = raylib_ffi::colors::ORANGE; }
 							if Vector3::from([x,0,z]) == gamestate.battleData.cursor { color = raylib_ffi::colors::GREEN; }
 						}
 					}
+					PlayerBattleState::Switch => {}
 					PlayerBattleState::Attack => {
-						if chosenMon == 0 {
-							let monster = gamestate.battleData.playerTeam.0[0].as_ref().unwrap();
+						if (chosenMon == 0 || chosenMon == 1) && gamestate.battleData.get_monster(TURN_SLOTS[chosenMon as usize]).is_some() {
+							let monster = gamestate.battleData.get_monster(TURN_SLOTS[chosenMon as usize]).unwrap();
 							//let monPosi = gamestate.battleData.objects["player_1"].position;
 							let monPosi = gamestate.battleData.movementCursor;
-							match monster.attacks[gamestate.battleData.attackChoice as usize] {
-								MonsterAttacks::Tackle => {
-									//* Player side */
-									if x > 3 && x < 8 {
-										if monPosi.x > 3.0 { color = raylib_ffi::colors::GREEN; }
-										else { color = raylib_ffi::colors::RED; }
-									}
-									//* Enemy side */
-									if x > 7 && x < 12 && z > (monPosi.z - 2.0) as i32 && z < (monPosi.z + 2.0) as i32 { color = raylib_ffi::colors::BLUE; }
-								}
-								MonsterAttacks::Scratch => {
-									//* Player side */
-									if x > 3 && x < 8 {
-										if monPosi.x > 3.0 && monPosi.x < 8.0 { color = raylib_ffi::colors::GREEN; }
-										else { color = raylib_ffi::colors::RED; }
-									}
-									//* Enemy side */
-									if x > 7 && x < 11 && z > (monPosi.z - 3.0) as i32 && z < (monPosi.z + 3.0) as i32 { color = raylib_ffi::colors::BLUE; }
-								}
-								MonsterAttacks::Growl => {
-									if x > 7 { color = raylib_ffi::colors::BLUE; }
-								}
-								MonsterAttacks::Leer => {
-									if x > 7 { color = raylib_ffi::colors::BLUE; }
-								}
-								MonsterAttacks::Leafage => {
-									let mut isFront = false;
-									if monPosi.x > 3.0 {
-										isFront = true;
-										if x > 11 && x < 16 { color = raylib_ffi::colors::ORANGE; }
-									}
-									if monPosi.x < 4.0 {
-										isFront = false;
-										if x > 7 && x < 12 { color = raylib_ffi::colors::ORANGE; }
-									}
-									if x > (gamestate.battleData.cursor.x - 2.0) as i32 && x < (gamestate.battleData.cursor.x + 2.0) as i32 && z > (gamestate.battleData.cursor.z - 2.0) as i32 && z < (gamestate.battleData.cursor.z + 2.0) as i32 {
-										if isFront  && 11 < gamestate.battleData.cursor.x as i32 { color = raylib_ffi::colors::BLUE; }
-										if isFront  && 12 > gamestate.battleData.cursor.x as i32 { color = raylib_ffi::colors::RED; }
-										if !isFront && 11 < gamestate.battleData.cursor.x as i32 { color = raylib_ffi::colors::RED; }
-										if !isFront && 12 > gamestate.battleData.cursor.x as i32 { color = raylib_ffi::colors::BLUE; }
-									}
-								}
-								MonsterAttacks::Ember => {
-									if monPosi.z as i32 == z && x > monPosi.x as i32 { color = raylib_ffi::colors::BLUE; }
-								}
-								MonsterAttacks::Aquajet => {
-									if monPosi.x as i32 > 3 {
-										if x > 3 && x < 8 { color = raylib_ffi::colors::GREEN; }
-										if x > 11 && z > (monPosi.z - 2.0) as i32 && z < (monPosi.z + 2.0) as i32 { color = raylib_ffi::colors::BLUE; }
-									} else {
-										if x < 4 { color = raylib_ffi::colors::GREEN; }
-										if x > 7 && x < 12 && z > (monPosi.z - 2.0) as i32 && z < (monPosi.z + 2.0) as i32 { color = raylib_ffi::colors::BLUE; }
-									}
-								}
-								_ => {}
+							let cursor = gamestate.battleData.cursor;
+							let attack = &monster.attacks[gamestate.battleData.attackChoice as usize];
+							let definition = attack.data();
+							let area = definition.get_area(monPosi.x as i32);
+
+							//* Player side */
+							if definition.position != AttackPosition::Any && x < 8 && definition.usable_from(x) {
+								if BattleData::can_use_attack(attack, monPosi, true) { color = raylib_ffi::colors::GREEN; }
+								else { color = raylib_ffi::colors::RED; }
+							}
+
+							//* Enemy side */
+							if area.anchor == AreaAnchor::Cursor && x > 7 && area.cursor_in_bounds([x - 8, z]) { color = raylib_ffi::colors::ORANGE; }
+							if BattleData::attack_covers(attack, monPosi, cursor, [x,0,z], true) {
+								color = raylib_ffi::colors::BLUE;
+							} else if area.anchor == AreaAnchor::Cursor {
+								//* Show where the attack would land if the cursor is out of bounds */
+								let mut unbounded = area.clone();
+								unbounded.bounds = [0,7];
+								if unbounded.covers([monPosi.x as i32, monPosi.z as i32], [cursor.x as i32 - 8, cursor.z as i32], [x - 8, z]) { color = raylib_ffi::colors::RED; }
 							}
 							if gamestate.battleData.movementCursor != (Vector3{x:-1.0,y:-1.0,z:-1.0}) && gamestate.battleData.movementCursor == [x,0,z].into() {
 								color = raylib_ffi::colors::PURPLE;
@@ -497,7 +861,7 @@ pub fn draw(gamestate: &mut data::Gamestate) {
 				);
 
 				model.draw_ex(
-					Vector3::from([x,0,z]) + gamestate.camera.position - Vector3{x:7.5,y:0.0,z:4.5},
+					Vector3::from([x,0,z]) + gamestate.camera.position - Vector3{x:7.5,y:0.0 - (tile.elevation as f32 * arena::ELEVATION_HEIGHT),z:4.5},
 					Vector3{x:0.0,y:1.0,z:0.0},
 					0.0,
 					Vector3{x:1.0,y:1.0,z:1.0},
@@ -507,10 +871,17 @@ pub fn draw(gamestate: &mut data::Gamestate) {
 
 			//* Objects */
 			let mut obj: Option<BattleObject> = None;
-			for (_, object) in gamestate.battleData.objects.iter() {
+			let mut objectID = "".to_string();
+			for (id, object) in gamestate.battleData.objects.iter() {
 				let objPos: [i32;3] = object.position.into();
-				if [x-1,0,z] == objPos { obj = Some(object.clone()); }
+				if [x-1,0,z] == objPos {
+					obj = Some(object.clone());
+					objectID = id.to_string();
+				}
 			}
+			//* Hidden while flashing */
+			let step = gamestate.battleData.animations.front();
+			if step.is_some() && step.unwrap().flashing(&objectID) { obj = None; }
 			// DRAW
 			if obj.is_some() {
 				//* Update material */
@@ -528,7 +899,11 @@ pub fn draw(gamestate: &mut data::Gamestate) {
 				model.set_material_texture(texture);
 
 				//* Draw */
-				let position = obj.as_ref().unwrap().position + gamestate.camera.position - Vector3{x:7.5,y:0.0,z:4.0};
+				let mut position = obj.as_ref().unwrap().position;
+				if step.is_some() { position = position + step.unwrap().offset(&objectID, position); }
+				let key: [i32;3] = position.round().into();
+				let elevation = gamestate.battleData.tiles.get(&key).map(|t| t.elevation).unwrap_or(0);
+				let position = position + gamestate.camera.position - Vector3{x:7.5,y:0.0 - (elevation as f32 * arena::ELEVATION_HEIGHT),z:4.0};
 				model.draw_ex(
 					position,
 					Vector3{x:1.0,y:0.0,z:0.0},
@@ -542,11 +917,31 @@ pub fn draw(gamestate: &mut data::Gamestate) {
 			//TODO
 		}
 	}
+
+	//* Projectile */
+	let step = gamestate.battleData.animations.front();
+	if step.is_some() && gamestate.battleData.objects.contains_key(&step.unwrap().objectID) {
+		let projectile = step.unwrap().projectile(gamestate.battleData.objects[&step.unwrap().objectID].position);
+		if projectile.is_some() {
+			let (position, attackType) = projectile.unwrap();
+			raylib::draw_billboard(
+				&gamestate.camera,
+				gamestate.graphics.textures["battle_projectile"],
+				position + gamestate.camera.position - Vector3{x:7.5,y:0.0 - animation::PROJECTILE_HEIGHT,z:4.0},
+				0.5,
+				animation::type_color(attackType),
+			);
+		}
+	}
 }
 
 pub fn draw_ui(gamestate: &mut data::Gamestate) {
+	let forced = gamestate.battleData.pending_replacement().is_some();
+	let mut state = gamestate.battleData.playerState.clone();
+	if forced { state = PlayerBattleState::Switch; }
+
 	//* Current combat state */
-	match gamestate.battleData.playerState {
+	match state {
 		PlayerBattleState::Movement => {
 			gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
 				Rectangle{
@@ -573,6 +968,36 @@ pub fn draw_ui(gamestate: &mut data::Gamestate) {
 				(20.0 * data::get_screenratio()) as i32,
 				raylib_ffi::colors::BLACK,
 			);
+			let actions = match gamestate.battleData.battleType {
+				BattleType::Wild { .. }	=> "battle_text_wild",
+				_						=> "battle_text_actions",
+			};
+			gamestate.graphics.fonts["default"].draw(
+				&gamestate.localization[actions],
+				data::get_screenwidth() - 150,
+				(245.0 * data::get_screenratio()) as i32,
+				(20.0 * data::get_screenratio()) as i32,
+				raylib_ffi::colors::BLACK,
+			);
+		}
+		PlayerBattleState::Switch => {
+			gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
+				Rectangle{
+					x: data::get_screenwidth() as f32 - 200.0,
+					y: 100.0 * data::get_screenratio(),
+					width: 300.0 * data::get_screenratio(),
+					height: 100.0 * data::get_screenratio(),
+				},
+				0.0,
+			);
+			let text = if forced { "battle_text_replace" } else { "battle_text_switch" };
+			gamestate.graphics.fonts["default"].draw(
+				&gamestate.localization[text],
+				data::get_screenwidth() - 150,
+				(145.0 * data::get_screenratio()) as i32,
+				(20.0 * data::get_screenratio()) as i32,
+				raylib_ffi::colors::BLACK,
+			);
 		}
 		PlayerBattleState::Attack => {
 			gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
@@ -604,95 +1029,71 @@ pub fn draw_ui(gamestate: &mut data::Gamestate) {
 	}
 
 	//* Battle status */
-	//TODO Double battle
-	// Player side
-	gamestate.graphics.textures["ui_battlestatus_general"].draw_ex(
-		Vector2{
-			x:384.0 * data::get_screenratio() as f32,
-			y: 96.0 * data::get_screenratio() as f32,
-		},
-		180.0,
-		1.0,
-	);
+	for i in 0..2 {
+		let playermon = gamestate.battleData.get_monster(TURN_SLOTS[i]).cloned();
+		if playermon.is_some() { draw_status(gamestate, &playermon.unwrap(), true, i as f32); }
 
-	//TODO Have a look to see if i can do this better
-	let playermon = gamestate.battleData.playerTeam.0[0].as_ref().unwrap();
-	let enemymon = gamestate.battleData.enemyTeam.0[0].as_ref().unwrap();
-	let name = playermon.get_name();
-	let str: String;
-	if name.1 { str = name.0; }
-	else { str = gamestate.localization[&name.0].to_string(); }
-
-	gamestate.graphics.fonts["default"].draw_pro(
-		&str,
-		Vector2 {
-			x: 26.0 * data::get_screenratio() as f32,
-			y: 26.0 * data::get_screenratio() as f32,
-		},
-		0.0,
-		16.0 * data::get_screenratio() as f32,
-		0.0,
-		raylib_ffi::colors::BLACK,
-	);
+		let enemymon = gamestate.battleData.get_monster(TURN_SLOTS[i + 2]).cloned();
+		if enemymon.is_some() { draw_status(gamestate, &enemymon.unwrap(), false, i as f32); }
+	}
 
-	let hpStr = playermon.hpCur.to_string() + " / " + &playermon.hpMax.to_string();
-	gamestate.graphics.fonts["default"].draw_pro(
-		&hpStr,
-		Vector2 {
-			x: 26.0 * data::get_screenratio() as f32,
-			y: 58.0 * data::get_screenratio() as f32,
-		},
-		0.0,
-		16.0 * data::get_screenratio() as f32,
-		0.0,
-		raylib_ffi::colors::BLACK,
-	);
+	draw_log(gamestate);
 
-	// Enemy side
-	gamestate.graphics.textures["ui_battlestatus_general"].draw_ex(
-		Vector2{
-			x: data::get_screenwidth() as f32 - (384.0 * data::get_screenratio() as f32),
-			y: 0.0,
-		},
-		0.0,
-		1.0,
-	);
-	let enemyStr = gamestate.localization[&gamestate.battleData.enemyTeam.0[0].as_ref().unwrap().species.to_string()].to_string();
-	let offset = enemyStr.len() as f32 * 16.0;
-	gamestate.graphics.fonts["default"].draw_pro(
-		&enemyStr,
-		Vector2 {
-			//x: data::get_screenwidth() as f32 - (384.0 * data::get_screenratio() as f32) + (106.0 * data::get_screenratio() as f32),
-			x: data::get_screenwidth() as f32 - (26.0 + offset),
-			y: 26.0 * data::get_screenratio() as f32,
-		},
-		0.0,
-		16.0 * data::get_screenratio() as f32,
-		0.0,
-		raylib_ffi::colors::BLACK,
-	);
+	//* Switch UI */
+	if state == PlayerBattleState::Switch {
+		for i in 0..4 {
+			let monster = gamestate.battleData.playerTeam.0[i].clone();
+			if monster.is_none() { continue; }
+			let monster = monster.unwrap();
 
-	let enemymonhpStr = enemymon.hpCur.to_string() + " / " + &enemymon.hpMax.to_string();
-	gamestate.graphics.fonts["default"].draw_pro(
-		&enemymonhpStr,
-		Vector2 {
-			x: data::get_screenwidth() as f32 - (26.0 + offset),
-			y: 58.0 * data::get_screenratio() as f32,
-		},
-		0.0,
-		16.0 * data::get_screenratio() as f32,
-		0.0,
-		raylib_ffi::colors::BLACK,
-	);
+			let xOffset: f32;
+			if gamestate.battleData.switchChoice == i as i32 { xOffset = 50.0 * data::get_screenratio(); }
+			else { xOffset = 0.0; }
+
+			gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
+				Rectangle{
+					x: (-100.0 * data::get_screenratio()) + xOffset,
+					y: (220.0 * data::get_screenratio()) + ((70.0 * data::get_screenratio()) * i as f32),
+					width: 400.0 * data::get_screenratio(),
+					height: 60.0 * data::get_screenratio(),
+				},
+				0.0,
+			);
+
+			let name = monster.get_name();
+			let mut str: String;
+			if name.1 { str = name.0; }
+			else { str = gamestate.localization[&name.0].to_string(); }
+			str += &format!("  {} / {}", monster.hpCur, monster.hpMax);
+
+			let color = if gamestate.battleData.can_switch_to(i as i32) { raylib_ffi::colors::BLACK } else { raylib_ffi::colors::GRAY };
+			gamestate.graphics.fonts["default"].draw_pro(
+				&str,
+				Vector2{
+					x: (26.0 * data::get_screenratio()) + xOffset,
+					y: (240.0 * data::get_screenratio()) + ((70.0 * data::get_screenratio()) * i as f32),
+				},
+				0.0,
+				16.0 * data::get_screenratio(),
+				0.0,
+				color,
+			);
+		}
+		return;
+	}
 
 	//* Attack UI */
 	//TODO Edit this
+	let chosenMon = gamestate.battleData.choosing_slot().map(|s| s as i8).unwrap_or(-1);
+	let mut activeSlot = 0;
+	if chosenMon == 1 && gamestate.battleData.get_monster(TURN_SLOTS[1]).is_some() { activeSlot = 1; }
+	if gamestate.battleData.get_monster(TURN_SLOTS[activeSlot]).is_none() { return; }
 	for i in 0..4 {
 		let yOffset: i32;
 		if gamestate.battleData.attackChoice == i && gamestate.battleData.playerState == PlayerBattleState::Attack { yOffset = (50.0 * data::get_screenratio()) as i32; }
 		else { yOffset = 0; }
 
-		let attacks = gamestate.battleData.playerTeam.0[0].clone().unwrap().attacks;
+		let attacks = gamestate.battleData.get_monster(TURN_SLOTS[activeSlot]).unwrap().attacks.clone();
 		gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
 			Rectangle{
 				x: (25.0 * data::get_screenratio()) + ((200.0 * data::get_screenratio()) * i as f32),
@@ -718,48 +1119,175 @@ pub fn draw_ui(gamestate: &mut data::Gamestate) {
 	}
 }
 
-pub fn calc_turn_order(monsters: [Option<monsters::Monster>;4]) -> [i8;4] {
-	//* This is the stupid way of doing it, but i am that... so */
-	let mut result: [i8;4] = [-1,-1,-1,-1];
-
-	let mut largest = 0;
-	let mut member: i32 = -1;
-	for x in 0..4 {
-		if monsters[x as usize].is_none() { continue; }
-		if monsters[x as usize].clone().unwrap().speed > largest { member = x; }
-	}
-	if member == -1 { return result; }
-	result[0] = member as i8;
-	largest = 0;
-	member = -1;
-
-	for x in 0..4 {
-		if monsters[x as usize].is_none() { continue; }
-		if x == result[0] { continue; }
-		if monsters[x as usize].clone().unwrap().speed > largest { member = x as i32; }
-	}
-	if member == -1 { return result; }
-	result[1] = member as i8;
-	largest = 0;
-	member = -1;
-
-	for x in 0..4 {
-		if monsters[x as usize].is_none() { continue; }
-		if x == result[0] || x == result[1] { continue; }
-		if monsters[x as usize].clone().unwrap().speed > largest { member = x as i32; }
-	}
-	if member == -1 { return result; }
-	result[2] = member as i8;
-	largest = 0;
-	member = -1;
-	
-	for x in 0..4 {
-		if monsters[x as usize].is_none() { continue; }
-		if x == result[0] || x == result[1] || x == result[2] { continue; }
-		if monsters[x as usize].clone().unwrap().speed > largest { member = x as i32; }
+/// Draws the newest lines of the battle log, or older ones if it's been scrolled back.
+fn draw_log(gamestate: &mut data::Gamestate) {
+	let lines = log::to_lines(&gamestate.battleData.events, &gamestate.localization);
+	let end = lines.len().saturating_sub(gamestate.battleData.logScroll.max(0) as usize);
+	let start = end.saturating_sub(log::VISIBLE_LINES);
+
+	gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
+		Rectangle{
+			x: data::get_screenwidth() as f32 - (440.0 * data::get_screenratio()),
+			y: data::get_screenheight() as f32 - (200.0 * data::get_screenratio()),
+			width: 420.0 * data::get_screenratio(),
+			height: 180.0 * data::get_screenratio(),
+		},
+		0.0,
+	);
+	for (i, line) in lines[start..end].iter().enumerate() {
+		gamestate.graphics.fonts["default"].draw_pro(
+			line,
+			Vector2{
+				x: data::get_screenwidth() as f32 - (420.0 * data::get_screenratio()),
+				y: data::get_screenheight() as f32 - (182.0 * data::get_screenratio()) + ((24.0 * data::get_screenratio()) * i as f32),
+			},
+			0.0,
+			16.0 * data::get_screenratio(),
+			0.0,
+			raylib_ffi::colors::BLACK,
+		);
+	}
+}
+
+/// Draws the status panel for a monster.
+/// <br>``row`` is the panel's position from the top of the screen, for double battles.
+fn draw_status(gamestate: &mut data::Gamestate, monster: &monsters::Monster, isPlayer: bool, row: f32) {
+	let rowOffset = row * 96.0 * data::get_screenratio() as f32;
+
+	if isPlayer {
+		gamestate.graphics.textures["ui_battlestatus_general"].draw_ex(
+			Vector2{
+				x:384.0 * data::get_screenratio() as f32,
+				y: (96.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			180.0,
+			1.0,
+		);
+
+		let name = monster.get_name();
+		let str: String;
+		if name.1 { str = name.0; }
+		else { str = gamestate.localization[&name.0].to_string(); }
+
+		gamestate.graphics.fonts["default"].draw_pro(
+			&str,
+			Vector2 {
+				x: 26.0 * data::get_screenratio() as f32,
+				y: (26.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			0.0,
+			16.0 * data::get_screenratio() as f32,
+			0.0,
+			raylib_ffi::colors::BLACK,
+		);
+
+		let hpStr = monster.hpCur.to_string() + " / " + &monster.hpMax.to_string();
+		gamestate.graphics.fonts["default"].draw_pro(
+			&hpStr,
+			Vector2 {
+				x: 26.0 * data::get_screenratio() as f32,
+				y: (58.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			0.0,
+			16.0 * data::get_screenratio() as f32,
+			0.0,
+			raylib_ffi::colors::BLACK,
+		);
+
+		let conditionStr = conditions_to_string(monster, &gamestate.localization) + &stages_to_string(monster, &gamestate.localization);
+		gamestate.graphics.fonts["default"].draw_pro(
+			&conditionStr,
+			Vector2 {
+				x: 26.0 * data::get_screenratio() as f32,
+				y: (80.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			0.0,
+			16.0 * data::get_screenratio() as f32,
+			0.0,
+			raylib_ffi::colors::MAROON,
+		);
+	} else {
+		gamestate.graphics.textures["ui_battlestatus_general"].draw_ex(
+			Vector2{
+				x: data::get_screenwidth() as f32 - (384.0 * data::get_screenratio() as f32),
+				y: rowOffset,
+			},
+			0.0,
+			1.0,
+		);
+
+		let enemyStr = gamestate.localization[&monster.species.to_string()].to_string();
+		let offset = enemyStr.len() as f32 * 16.0;
+		gamestate.graphics.fonts["default"].draw_pro(
+			&enemyStr,
+			Vector2 {
+				x: data::get_screenwidth() as f32 - (26.0 + offset),
+				y: (26.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			0.0,
+			16.0 * data::get_screenratio() as f32,
+			0.0,
+			raylib_ffi::colors::BLACK,
+		);
+
+		let hpStr = monster.hpCur.to_string() + " / " + &monster.hpMax.to_string();
+		gamestate.graphics.fonts["default"].draw_pro(
+			&hpStr,
+			Vector2 {
+				x: data::get_screenwidth() as f32 - (26.0 + offset),
+				y: (58.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			0.0,
+			16.0 * data::get_screenratio() as f32,
+			0.0,
+			raylib_ffi::colors::BLACK,
+		);
+
+		let conditionStr = conditions_to_string(monster, &gamestate.localization) + &stages_to_string(monster, &gamestate.localization);
+		gamestate.graphics.fonts["default"].draw_pro(
+			&conditionStr,
+			Vector2 {
+				x: data::get_screenwidth() as f32 - (26.0 + offset),
+				y: (80.0 * data::get_screenratio() as f32) + rowOffset,
+			},
+			0.0,
+			16.0 * data::get_screenratio() as f32,
+			0.0,
+			raylib_ffi::colors::MAROON,
+		);
 	}
-	if member == -1 { return result; }
-	result[3] = member as i8;
+}
 
-	return result;
-}
\ No newline at end of file
+/// Creates the status panel text for the input monster's conditions.
+fn conditions_to_string(monster: &monsters::Monster, localization: &HashMap<String, String>) -> String {
+	let mut output = String::new();
+
+	for condition in monster.conditions.iter() {
+		let key = "condition_".to_string() + &condition.to_string();
+		if !localization.contains_key(&key) { continue; }
+		if output != "" { output += " "; }
+
+		output += &localization[&key];
+		match condition {
+			monsters::MonsterConditions::Burned { stacks } => output += &format!(" x{}", stacks),
+			monsters::MonsterConditions::None => {}
+		}
+	}
+
+	return output;
+}
+
+/// Creates the status panel text for the input monster's stat stages.
+fn stages_to_string(monster: &monsters::Monster, localization: &HashMap<String, String>) -> String {
+	let mut output = String::new();
+
+	let stats = [BattleStat::PhysicalAttack, BattleStat::PhysicalDefense, BattleStat::SpecialAttack, BattleStat::SpecialDefense, BattleStat::Speed];
+	for stat in stats {
+		let stage = monster.statChanges[stat as usize];
+		if stage == 0 { continue; }
+
+		output += &format!(" {} {:+}", localization[&("stage_".to_string() + &stat.to_string())], stage);
+	}
+
+	return output;
+}
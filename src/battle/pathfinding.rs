@@ -0,0 +1,190 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap}};
+
+use crate::raylib::vectors::Vector3;
+use super::{arena::{self, Terrain}, BattleData};
+
+
+//= Constants
+/// Offsets of the tiles a monster can step to from it's current one.
+const NEIGHBOURS: [[i32;3];4] = [[1,0,0], [0,0,1], [-1,0,0], [0,0,-1]];
+
+
+//= Procedures
+
+/// Returns the cost for the battle object to step onto the input tile, or ``None`` if it can't.
+/// <br>The tile has to be on it's half, open and not taken by another object. Water and climbing cost extra.
+pub fn step_cost(battle: &BattleData, objectID: &str, from: [i32;3], to: [i32;3]) -> Option<i32> {
+	let local = BattleData::local_position(to, battle.is_player(objectID));
+	if local[0] < 0 || local[0] > 7 || local[1] < 0 || local[1] > 7 { return None; }
+
+	let tile = battle.tiles.get(&to)?;
+	if tile.blocked || battle.is_occupied(Vector3::from(to), objectID) { return None; }
+
+	let mut cost = 1;
+	if tile.terrain == Terrain::Water { cost += arena::WATER_PENALTY; }
+	let climb = tile.elevation - battle.tiles.get(&from).map(|t| t.elevation).unwrap_or(0);
+	if climb > 0 { cost += climb * arena::CLIMB_PENALTY; }
+
+	return Some(cost);
+}
+
+/// Returns every tile the battle object can reach this turn and the cost of getting there, including it's current one.
+pub fn reachable_tiles(battle: &BattleData, objectID: &str) -> HashMap<[i32;3], i32> {
+	let mut output: HashMap<[i32;3], i32> = HashMap::new();
+
+	let monster = battle.get_monster(objectID);
+	if monster.is_none() { return output; }
+	let distance = monster.unwrap().get_move_distance();
+	let start: [i32;3] = battle.objects[objectID].position.into();
+
+	//* Dijkstra, since water and climbing make some steps cost more */
+	let mut queue: BinaryHeap<Reverse<(i32, [i32;3])>> = BinaryHeap::new();
+	output.insert(start, 0);
+	queue.push(Reverse((0, start)));
+
+	while let Some(Reverse((cost, tile))) = queue.pop() {
+		if cost > output[&tile] { continue; }
+
+		for offset in NEIGHBOURS.iter() {
+			let next = [tile[0] + offset[0], tile[1], tile[2] + offset[2]];
+			let step = step_cost(battle, objectID, tile, next);
+			if step.is_none() { continue; }
+
+			let total = cost + step.unwrap();
+			if total > distance || output.get(&next).map(|c| *c <= total).unwrap_or(false) { continue; }
+			output.insert(next, total);
+			queue.push(Reverse((total, next)));
+		}
+	}
+
+	return output;
+}
+
+/// Finds the cheapest path for the battle object to the goal that's within it's move distance.
+/// <br>The path starts with the object's current tile and ends with the goal.
+pub fn find_path(battle: &BattleData, objectID: &str, goal: Vector3) -> Option<Vec<Vector3>> {
+	let monster = battle.get_monster(objectID)?;
+	let distance = monster.get_move_distance();
+	let start: [i32;3] = battle.objects[objectID].position.into();
+	let goal: [i32;3] = goal.into();
+
+	//* A* with the Manhattan distance, which never overestimates since every step costs at least 1 */
+	let heuristic = |tile: [i32;3]| -> i32 { return (goal[0] - tile[0]).abs() + (goal[2] - tile[2]).abs(); };
+	let mut costs: HashMap<[i32;3], i32> = HashMap::new();
+	let mut previous: HashMap<[i32;3], [i32;3]> = HashMap::new();
+	let mut queue: BinaryHeap<Reverse<(i32, i32, [i32;3])>> = BinaryHeap::new();
+	costs.insert(start, 0);
+	queue.push(Reverse((heuristic(start), 0, start)));
+
+	while let Some(Reverse((_, cost, tile))) = queue.pop() {
+		if tile == goal {
+			let mut output: Vec<Vector3> = vec![Vector3::from(tile)];
+			let mut current = tile;
+			while let Some(from) = previous.get(&current) {
+				output.insert(0, Vector3::from(*from));
+				current = *from;
+			}
+			return Some(output);
+		}
+		if cost > costs[&tile] { continue; }
+
+		for offset in NEIGHBOURS.iter() {
+			let next = [tile[0] + offset[0], tile[1], tile[2] + offset[2]];
+			let step = step_cost(battle, objectID, tile, next);
+			if step.is_none() { continue; }
+
+			let total = cost + step.unwrap();
+			if total + heuristic(next) > distance || costs.get(&next).map(|c| *c <= total).unwrap_or(false) { continue; }
+			costs.insert(next, total);
+			previous.insert(next, tile);
+			queue.push(Reverse((total + heuristic(next), total, next)));
+		}
+	}
+
+	return None;
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{battle::{ArenaType, BattleType, arena::BattleTile}, monsters::{MonsterTeam, tests::create_monster}};
+
+	/// Creates a wild battle on a flat arena, with the player's monster able to move 3 tiles.
+	fn create_battle() -> BattleData {
+		let mut team = MonsterTeam::new();
+		team.add_member(create_monster("mon_158", 10));
+
+		let mut battle = BattleData::init();
+		battle.start_seeded_battle(BattleType::Wild{ monster: create_monster("mon_155", 10), arena: ArenaType::Field }, &team, 1);
+		battle.playerTeam.0[0].as_mut().unwrap().speed = 100;
+
+		battle.tiles = HashMap::new();
+		for x in 0..16 {
+			for z in 0..8 { battle.tiles.insert([x, 0, z], BattleTile::create("battle_1")); }
+		}
+		return battle;
+	}
+
+	#[test]
+	fn reachable_within_move_distance() {
+		let battle = create_battle();
+		let reachable = reachable_tiles(&battle, "player_1");
+		assert_eq!(battle.get_monster("player_1").unwrap().get_move_distance(), 3);
+		assert_eq!(reachable[&[4, 0, 4]], 0);
+		assert_eq!(reachable[&[7, 0, 4]], 3);
+		assert_eq!(reachable[&[5, 0, 6]], 3);
+		assert!(!reachable.contains_key(&[8, 0, 4]));
+		assert!(!reachable.contains_key(&[6, 0, 6]));
+		assert_eq!(reachable.len(), 25);
+	}
+
+	#[test]
+	fn other_half_is_unreachable() {
+		let mut battle = create_battle();
+		battle.objects.get_mut("player_1").unwrap().position = Vector3{x:7.0,y:0.0,z:4.0};
+		let reachable = reachable_tiles(&battle, "player_1");
+		assert!(reachable.keys().all(|tile| tile[0] <= 7));
+		assert!(find_path(&battle, "player_1", Vector3{x:8.0,y:0.0,z:4.0}).is_none());
+	}
+
+	#[test]
+	fn blocked_tiles_and_water() {
+		let mut battle = create_battle();
+		battle.tiles.get_mut(&[5, 0, 4]).unwrap().blocked = true;
+		battle.tiles.get_mut(&[4, 0, 5]).unwrap().terrain = Terrain::Water;
+
+		let reachable = reachable_tiles(&battle, "player_1");
+		assert!(!reachable.contains_key(&[5, 0, 4]));
+		assert!(!reachable.contains_key(&[6, 0, 4]));
+		assert_eq!(reachable[&[4, 0, 5]], 1 + arena::WATER_PENALTY);
+		assert!(find_path(&battle, "player_1", Vector3{x:6.0,y:0.0,z:4.0}).is_none());
+	}
+
+	#[test]
+	fn climbing_costs_extra() {
+		let mut battle = create_battle();
+		battle.tiles.get_mut(&[5, 0, 4]).unwrap().elevation = 1;
+		let reachable = reachable_tiles(&battle, "player_1");
+		assert_eq!(reachable[&[5, 0, 4]], 1 + arena::CLIMB_PENALTY);
+	}
+
+	#[test]
+	fn path_goes_from_start_to_goal() {
+		let battle = create_battle();
+		let path = find_path(&battle, "player_1", Vector3{x:5.0,y:0.0,z:6.0}).unwrap();
+		assert_eq!(path.len(), 4);
+		assert!(path[0] == Vector3{x:4.0,y:0.0,z:4.0});
+		assert!(path[3] == Vector3{x:5.0,y:0.0,z:6.0});
+		for step in path.windows(2) {
+			assert_eq!((step[1].x - step[0].x).abs() + (step[1].z - step[0].z).abs(), 1.0);
+		}
+	}
+
+}
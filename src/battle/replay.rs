@@ -0,0 +1,372 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{fs::{self, read_to_string}, str::FromStr};
+use serde_json::{json, Value};
+
+use crate::{monsters::{Monster, MonsterTeam, MonsterSpecies, MonsterAttacks, MonsterAbilities, MonsterConditions, natures::MonsterNature}, raylib::vectors::Vector3, utilities::debug};
+use super::{BattleType, ArenaType, engine::BattleAction};
+
+
+//= Constants
+/// Folder replays are recorded to.
+pub const REPLAY_FOLDER: &str = "replays";
+
+
+//= Structures
+
+/// Everything needed to play a battle out again exactly.
+/// <br>The enemy teams are the ones actually used, so the difficulty doesn't matter when playing back.
+#[derive(Clone)]
+pub struct Replay {
+	pub seed: u64,
+	pub battle: BattleType,
+	pub playerTeam: MonsterTeam,
+	/// Every action that was performed, in order.
+	pub actions: Vec<BattleAction>,
+}
+
+/// A replay being played back.
+#[derive(Clone)]
+pub struct Playback {
+	pub replay: Replay,
+	/// Index of the next action to perform.
+	pub next: usize,
+	/// Frames until the next action is performed.
+	pub timer: i32,
+}
+
+
+//= Procedures
+
+impl Replay {
+
+	/// Creates an empty replay for a battle starting with the input teams.
+	pub fn new(seed: u64, battle: &BattleType, playerTeam: &MonsterTeam, enemyTeams: &[MonsterTeam;2]) -> Self {
+		let battle = match battle {
+			BattleType::Single { trainerName, arena, reward, .. } => BattleType::Single {
+				trainerName: trainerName.to_string(),
+				easyTeam: enemyTeams[0].clone(),
+				mediumTeam: enemyTeams[0].clone(),
+				hardTeam: enemyTeams[0].clone(),
+				arena: *arena,
+				reward: *reward,
+			},
+			BattleType::Double { trainerName, singleTrainer, arena, reward, .. } => BattleType::Double {
+				trainerName: trainerName.clone(),
+				singleTrainer: *singleTrainer,
+				easyTeam: enemyTeams.clone(),
+				mediumTeam: enemyTeams.clone(),
+				hardTeam: enemyTeams.clone(),
+				arena: *arena,
+				reward: *reward,
+			},
+			_ => battle.clone(),
+		};
+
+		return Replay {
+			seed,
+			battle,
+			playerTeam: playerTeam.clone(),
+			actions: Vec::new(),
+		}
+	}
+
+	/// Writes the replay to a new file in ``REPLAY_FOLDER`` and returns it's path.
+	pub fn save(&self) -> Option<String> {
+		let _ = fs::create_dir_all(REPLAY_FOLDER);
+		let path = format!("{}/battle_{}.json", REPLAY_FOLDER, chrono::Local::now().format("%Y%m%d_%H%M%S_%3f"));
+
+		let (battleType, trainer, arena, reward, enemy) = match &self.battle {
+			BattleType::Single { trainerName, mediumTeam, arena, reward, .. } => ("single", json!([trainerName]), *arena, *reward, json!([team_to_json(mediumTeam)])),
+			BattleType::Double { trainerName, mediumTeam, arena, reward, .. } => ("double", json!(trainerName), *arena, *reward, json!([team_to_json(&mediumTeam[0]), team_to_json(&mediumTeam[1])])),
+			BattleType::Wild { monster, arena } => ("wild", json!([]), *arena, 0, json!([[monster_to_json(monster)]])),
+			BattleType::Empty => return None,
+		};
+		let file = json!({
+			"seed": self.seed,
+			"type": battleType,
+			"trainer": trainer,
+			"arena": arena.to_string(),
+			"reward": reward,
+			"player": team_to_json(&self.playerTeam),
+			"enemy": enemy,
+			"actions": self.actions.iter().map(|a| action_to_json(a)).collect::<Vec<Value>>(),
+		});
+
+		if fs::write(&path, serde_json::to_string_pretty(&file).unwrap()).is_err() {
+			debug::log(&format!("[ERROR] - Failed to write replay file \"{}\".\n", path));
+			return None;
+		}
+		return Some(path);
+	}
+
+	/// Loads a replay from the input file.
+	pub fn load(path: &str) -> Option<Self> {
+		//* Attempt to load replay file */
+		let fileResult = read_to_string(path);
+		if fileResult.is_err() {
+			debug::log(&format!("[ERROR] - Failed to load replay file \"{}\".\n", path));
+			return None;
+		}
+
+		//* Convert to JSON and read */
+		let jsonResult = serde_json::from_str::<Value>(&fileResult.unwrap());
+		if jsonResult.is_err() {
+			debug::log(&format!("[ERROR] - Replay file \"{}\" isn't valid JSON.\n", path));
+			return None;
+		}
+		let jsonFile = jsonResult.unwrap();
+
+		let arena = ArenaType::from(jsonFile["arena"].as_str().unwrap_or("field"));
+		let reward = jsonFile["reward"].as_i64().unwrap_or(0) as i32;
+		let trainers: Vec<String> = jsonFile["trainer"].as_array().map(|a| a.iter().map(|t| t.as_str().unwrap_or("").to_string()).collect()).unwrap_or(Vec::new());
+		let trainer = |i: usize| -> String { return trainers.get(i).cloned().unwrap_or("".to_string()); };
+		let enemy = |i: usize| -> MonsterTeam { return parse_team(&jsonFile["enemy"][i]); };
+
+		let battle = match jsonFile["type"].as_str().unwrap_or("") {
+			"single" => BattleType::Single {
+				trainerName: trainer(0),
+				easyTeam: enemy(0),
+				mediumTeam: enemy(0),
+				hardTeam: enemy(0),
+				arena,
+				reward,
+			},
+			"double" => BattleType::Double {
+				trainerName: [trainer(0), trainer(1)],
+				singleTrainer: trainer(1) == "",
+				easyTeam: [enemy(0), enemy(1)],
+				mediumTeam: [enemy(0), enemy(1)],
+				hardTeam: [enemy(0), enemy(1)],
+				arena,
+				reward,
+			},
+			"wild" => {
+				let monster = enemy(0).0[0].clone();
+				if monster.is_none() {
+					debug::log(&format!("[ERROR] - Replay file \"{}\" has no wild monster.\n", path));
+					return None;
+				}
+				BattleType::Wild { monster: monster.unwrap(), arena }
+			}
+			_ => {
+				debug::log(&format!("[ERROR] - Replay file \"{}\" has an invalid battle type.\n", path));
+				return None;
+			}
+		};
+
+		let mut actions: Vec<BattleAction> = Vec::new();
+		for i in jsonFile["actions"].as_array().unwrap_or(&Vec::new()) {
+			let action = parse_action(i);
+			if action.is_none() {
+				debug::log(&format!("[ERROR] - Replay file \"{}\" has an invalid action.\n", path));
+				return None;
+			}
+			actions.push(action.unwrap());
+		}
+
+		return Some(Replay {
+			seed: jsonFile["seed"].as_u64().unwrap_or(0),
+			battle,
+			playerTeam: parse_team(&jsonFile["player"]),
+			actions,
+		});
+	}
+
+}
+
+/// Converts a team into a JSON array of it's monsters.
+fn team_to_json(team: &MonsterTeam) -> Value {
+	return Value::Array(team.0.iter().filter(|m| m.is_some()).map(|m| monster_to_json(m.as_ref().unwrap())).collect());
+}
+
+/// Converts a monster into JSON, keeping everything that can change between battles.
+fn monster_to_json(monster: &Monster) -> Value {
+	let conditions: Vec<Value> = monster.conditions.iter().filter_map(|c| match c {
+		MonsterConditions::Burned { stacks }	=> Some(json!({"id": "burned", "stacks": stacks})),
+		MonsterConditions::None					=> None,
+	}).collect();
+
+	return json!({
+		"species": monster.species.to_string(),
+		"nickname": monster.nickname,
+		"level": monster.level,
+		"experience": monster.experience,
+		"nature": monster.nature.to_string(),
+		"ivs": monster.ivs,
+		"evs": monster.evs,
+		"health": monster.hpCur,
+		"conditions": conditions,
+		"ability": if monster.ability == MonsterAbilities::None { "none".to_string() } else { monster.ability.to_string() },
+		"attacks": monster.attacks.iter().map(|a| if *a == MonsterAttacks::None { "empty".to_string() } else { a.to_string() }).collect::<Vec<String>>(),
+	});
+}
+
+/// Converts input JSON value into a team.
+fn parse_team(value: &Value) -> MonsterTeam {
+	let mut output = MonsterTeam::new();
+	for i in value.as_array().unwrap_or(&Vec::new()) {
+		let monster = parse_monster(i);
+		if monster.is_some() { output.add_member(monster.unwrap()); }
+	}
+	return output;
+}
+
+/// Converts input JSON value into a monster.
+fn parse_monster(value: &Value) -> Option<Monster> {
+	let species = MonsterSpecies::from_str(value["species"].as_str().unwrap_or(""));
+	if species.is_err() {
+		debug::log("[ERROR] - Replay has a monster with an invalid species.\n");
+		return None;
+	}
+
+	let stats = |key: &str| -> [i32;6] {
+		let mut output = [0;6];
+		for (i, v) in value[key].as_array().unwrap_or(&Vec::new()).iter().enumerate().take(6) { output[i] = v.as_i64().unwrap_or(0) as i32; }
+		return output;
+	};
+	let nature = MonsterNature::from_str(value["nature"].as_str().unwrap_or("")).ok();
+
+	let mut monster = Monster::new(species.unwrap(), value["level"].as_i64().unwrap_or(1) as i32, nature, Some(stats("ivs")));
+	monster.evs = stats("evs");
+	monster.calculate_stats();
+	monster.nickname = value["nickname"].as_str().unwrap_or("").to_string();
+	monster.experience = value["experience"].as_i64().unwrap_or(monster.experience as i64) as i32;
+	monster.hpCur = value["health"].as_i64().unwrap_or(monster.hpMax as i64) as i32;
+
+	monster.ability = MonsterAbilities::from_str(value["ability"].as_str().unwrap_or("")).unwrap_or(monster.ability);
+	for i in value["conditions"].as_array().unwrap_or(&Vec::new()) {
+		if i["id"].as_str() == Some("burned") { monster.add_condition(MonsterConditions::Burned { stacks: i["stacks"].as_i64().unwrap_or(1) as i32 }); }
+	}
+	for (i, a) in value["attacks"].as_array().unwrap_or(&Vec::new()).iter().enumerate().take(4) {
+		monster.attacks[i] = MonsterAttacks::from_str(a.as_str().unwrap_or("empty")).unwrap_or(MonsterAttacks::None);
+	}
+
+	return Some(monster);
+}
+
+/// Converts an action into JSON.
+fn action_to_json(action: &BattleAction) -> Value {
+	match action {
+		BattleAction::Attack { position, attack, cursor } => {
+			let position: [i32;3] = (*position).into();
+			let cursor: [i32;3] = (*cursor).into();
+			let attack = if *attack == MonsterAttacks::None { "empty".to_string() } else { attack.to_string() };
+			return json!({"type": "attack", "position": position, "attack": attack, "cursor": cursor});
+		}
+		BattleAction::Switch { num }	=> return json!({"type": "switch", "num": num}),
+		BattleAction::Flee				=> return json!({"type": "flee"}),
+		BattleAction::Capture			=> return json!({"type": "capture"}),
+		BattleAction::Pass				=> return json!({"type": "pass"}),
+	}
+}
+
+/// Converts input JSON value into an action.
+fn parse_action(value: &Value) -> Option<BattleAction> {
+	let tile = |key: &str| -> Option<Vector3> {
+		let array = value[key].as_array()?;
+		if array.len() != 3 { return None; }
+		return Some(Vector3::from([array[0].as_i64()? as i32, array[1].as_i64()? as i32, array[2].as_i64()? as i32]));
+	};
+
+	match value["type"].as_str().unwrap_or("") {
+		"attack" => {
+			let attack = MonsterAttacks::from_str(value["attack"].as_str().unwrap_or("")).ok()?;
+			return Some(BattleAction::Attack { position: tile("position")?, attack, cursor: tile("cursor")? });
+		}
+		"switch"	=> return Some(BattleAction::Switch { num: value["num"].as_i64()? as i32 }),
+		"flee"		=> return Some(BattleAction::Flee),
+		"capture"	=> return Some(BattleAction::Capture),
+		"pass"		=> return Some(BattleAction::Pass),
+		_			=> return None,
+	}
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{battle::{BattleData, ai}, monsters::tests::{create_monster, attack}, settings::Difficulty, utilities::random::Random};
+
+	/// Returns the health of every monster in the battle.
+	fn health(battle: &BattleData) -> Vec<i32> {
+		return battle.playerTeam.0.iter().chain(battle.enemyTeams[0].0.iter()).map(|m| m.as_ref().map(|m| m.hpCur).unwrap_or(0)).collect();
+	}
+
+	#[test]
+	fn actions_round_trip() {
+		let position = Vector3{x:4.0,y:0.0,z:3.0};
+		let cursor = Vector3{x:11.0,y:0.0,z:3.0};
+		let actions = [
+			BattleAction::Attack{ position, attack: attack("ember"), cursor },
+			BattleAction::Attack{ position, attack: MonsterAttacks::None, cursor },
+			BattleAction::Switch{ num: 2 },
+			BattleAction::Flee,
+			BattleAction::Capture,
+		];
+		for action in actions.iter() {
+			assert!(parse_action(&action_to_json(action)).as_ref() == Some(action));
+		}
+		assert!(parse_action(&json!({"type": "attack", "attack": "tackle"})).is_none());
+		assert!(parse_action(&json!({"type": "dance"})).is_none());
+	}
+
+	#[test]
+	fn monsters_round_trip() {
+		let mut monster = create_monster("mon_155", 12);
+		monster.evs = [4, 0, 0, 0, 0, 8];
+		monster.calculate_stats();
+		monster.hpCur = 5;
+		monster.nickname = "Cinder".to_string();
+
+		let parsed = parse_monster(&monster_to_json(&monster)).unwrap();
+		assert!(parsed.species == monster.species && parsed.nature == monster.nature);
+		assert_eq!((parsed.level, parsed.experience, parsed.hpCur, parsed.hpMax), (monster.level, monster.experience, monster.hpCur, monster.hpMax));
+		assert_eq!((parsed.ivs, parsed.evs), (monster.ivs, monster.evs));
+		assert!(parsed.attacks == monster.attacks);
+		assert_eq!(parsed.nickname, "Cinder");
+	}
+
+	#[test]
+	fn playback_matches_recording() {
+		let mut team = MonsterTeam::new();
+		team.add_member(create_monster("mon_158", 8));
+		let battleType = BattleType::Wild{ monster: create_monster("mon_152", 7), arena: ArenaType::Field };
+
+		//* Let the AI fight it out while recording */
+		let mut battle = BattleData::init();
+		battle.silent = true;
+		battle.animated = false;
+		battle.start_seeded_battle(battleType, &team, 77);
+		battle.recording = Some(Replay::new(77, &battle.battleType, &battle.playerTeam, &battle.enemyTeams));
+		let mut rng = Random::new(5);
+		for _ in 0..1000 {
+			if battle.outcome.is_some() { break; }
+			let action = ai::choose_action(&battle, Difficulty::Hard, &mut rng);
+			if !battle.apply_action(&action) { battle.apply_action(&BattleAction::Pass); }
+		}
+		assert!(battle.outcome.is_some());
+
+		//* Save, load and play it back */
+		let path = battle.recording.as_ref().unwrap().save().unwrap();
+		let replay = Replay::load(&path);
+		let _ = fs::remove_file(&path);
+		let replay = replay.unwrap();
+		assert_eq!(replay.actions.len(), battle.recording.as_ref().unwrap().actions.len());
+
+		let mut playback = BattleData::init();
+		playback.silent = true;
+		playback.animated = false;
+		playback.start_seeded_battle(replay.battle.clone(), &replay.playerTeam, replay.seed);
+		for action in replay.actions.iter() { assert!(playback.apply_action(action)); }
+
+		assert!(playback.outcome == battle.outcome);
+		assert_eq!(playback.roundTotal, battle.roundTotal);
+		assert_eq!(health(&playback), health(&battle));
+	}
+
+}
@@ -0,0 +1,95 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use crate::{monsters::Monster, events::{Event, EventChain, conditionals::Condition}};
+use super::{BattleData, BattleType};
+
+
+//= Procedures
+
+/// Returns the experience given for defeating the input monster.
+/// <br>Monsters owned by trainers give half again as much.
+pub fn experience_yield(defeated: &Monster, trainer: bool) -> i32 {
+	let mut experience = (defeated.species.data().expYield * defeated.level) as f32 / 7.0;
+	if trainer { experience *= 1.5; }
+
+	return experience.floor() as i32;
+}
+
+/// Splits the experience from every defeated enemy between the player's monsters that took part and haven't fainted.
+/// <br>The output is indexed by team position.
+pub fn distribute_experience(battle: &BattleData) -> [i32;4] {
+	let mut output = [0;4];
+
+	let trainer = match battle.battleType {
+		BattleType::Wild { .. } | BattleType::Empty => false,
+		_ => true,
+	};
+
+	let mut receivers: Vec<usize> = Vec::new();
+	for i in 0..4 {
+		let monster = battle.playerTeam.0[i].as_ref();
+		if battle.participants[i] && monster.is_some() && !monster.unwrap().is_fainted() { receivers.push(i); }
+	}
+	if receivers.len() == 0 { return output; }
+
+	for team in battle.enemyTeams.iter() {
+		for monster in team.0.iter() {
+			if monster.is_none() || !monster.as_ref().unwrap().is_fainted() { continue; }
+
+			let share = experience_yield(monster.as_ref().unwrap(), trainer) / receivers.len() as i32;
+			for i in receivers.iter() { output[*i] += share.max(1); }
+		}
+	}
+
+	return output;
+}
+
+/// Adds up the effort values from every defeated enemy for the player's monsters that took part and haven't fainted.
+/// <br>Unlike experience, every receiver gets the full amount. The output is indexed by team position.
+pub fn distribute_effort(battle: &BattleData) -> [[i32;6];4] {
+	let mut output = [[0;6];4];
+
+	for i in 0..4 {
+		let receiver = battle.playerTeam.0[i].as_ref();
+		if !battle.participants[i] || receiver.is_none() || receiver.unwrap().is_fainted() { continue; }
+
+		for team in battle.enemyTeams.iter() {
+			for monster in team.0.iter() {
+				if monster.is_none() || !monster.as_ref().unwrap().is_fainted() { continue; }
+
+				let evYield = monster.as_ref().unwrap().species.data().evYield;
+				for stat in 0..6 { output[i][stat] += evYield[stat]; }
+			}
+		}
+	}
+
+	return output;
+}
+
+/// Creates the event that gives out battle rewards.
+/// <br>When it's done it continues to the input event, if there is one.
+pub fn create_reward_event(experience: [i32;4], money: i32, nextEvent: Option<String>) -> Event {
+	let mut chain: Vec<EventChain> = Vec::new();
+
+	if money > 0 { chain.push(EventChain::Text { text: "battle_reward_money".to_string() }); }
+	for i in 0..4 {
+		if experience[i] > 0 { chain.push(EventChain::GiveExperience { monsterPosition: i, amount: experience[i] }); }
+	}
+	for i in 0..4 {
+		if experience[i] > 0 { chain.push(EventChain::ShowStats { monsterPosition: i }); }
+	}
+	if nextEvent.is_some() {
+		chain.push(EventChain::TestVariable {
+			variable: "battle_outcome".to_string(),
+			value: Condition::String("win".to_string()),
+			event: nextEvent.unwrap(),
+			position: 0,
+		});
+	}
+
+	return Event { chain };
+}
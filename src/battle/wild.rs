@@ -0,0 +1,37 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use crate::monsters::{Monster, MonsterConditions};
+
+
+//= Procedures
+
+/// Returns the chance out of ``1.0`` that the player's monster escapes from the wild monster.
+/// <br>Faster monsters always escape, and each failed attempt makes the next more likely.
+pub fn flee_chance(player: &Monster, wild: &Monster, attempts: i32) -> f32 {
+	if player.speed >= wild.speed { return 1.0; }
+
+	let odds = ((player.speed * 128) / wild.speed.max(1)) + (30 * attempts);
+	return (odds as f32 / 256.0).min(1.0);
+}
+
+/// Returns the chance out of ``1.0`` that the wild monster is captured.
+/// <br>Lower remaining health and active conditions raise the chance.
+pub fn capture_chance(wild: &Monster) -> f32 {
+	let hpMax = wild.hpMax.max(1) as f32;
+	let catchRate = wild.species.data().catchRate as f32;
+
+	let mut conditionBonus = 1.0;
+	for condition in wild.conditions.iter() {
+		match condition {
+			MonsterConditions::Burned { .. }	=> conditionBonus = f32::max(conditionBonus, 1.5),
+			MonsterConditions::None				=> {}
+		}
+	}
+
+	let odds = (((3.0 * hpMax) - (2.0 * wild.hpCur as f32)) * catchRate * conditionBonus) / (3.0 * hpMax);
+	return (odds / 255.0).clamp(0.0, 1.0);
+}
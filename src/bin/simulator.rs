@@ -0,0 +1,165 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{env, str::FromStr};
+
+use monorust::{world::World, monsters::MonsterTeam, settings::Difficulty, utilities::random::Random, battle::{self, BattleData, BattleType, BattleOutcome, engine::BattleAction}};
+
+
+//= Constants
+/// Actions after which a battle is given up on as unfinished.
+const MAX_ACTIONS: i32 = 2000;
+
+
+//= Structures
+
+/// Totals collected over every simulated battle.
+struct Summary {
+	wins: i32,
+	losses: i32,
+	unfinished: i32,
+
+	rounds: i32,
+	actions: i32,
+	/// Damage of every hit that landed.
+	hits: Vec<i32>,
+	/// Total damage dealt by the player and enemy sides.
+	damage: [i32;2],
+}
+
+
+//= Main
+/// Runs AI-vs-AI battles between two teams from ``battles.json`` without opening a window.
+/// <br>``simulator <map> <player battle> <enemy battle> [count] [seed] [difficulty]``
+fn main() {
+	let args: Vec<String> = env::args().collect();
+	if args.len() < 4 {
+		print!("Usage: simulator <map> <player battle> <enemy battle> [count] [seed] [difficulty]\n");
+		print!("The player side uses the team from the first battle, the enemy side plays the second battle as normal.\n");
+		return;
+	}
+
+	let count = args.get(4).map(|a| a.parse::<i32>().unwrap_or(100)).unwrap_or(100);
+	let seed = args.get(5).map(|a| a.parse::<u64>().unwrap_or(0)).unwrap_or(Random::from_time().seed);
+	let difficulty = args.get(6).map(|a| Difficulty::from_str(a).unwrap_or(Difficulty::Medium)).unwrap_or(Difficulty::Medium);
+
+	//* Load battles, anything the data leaves out is rolled from the seed so the same seed gives the same teams */
+	let mut world = World::empty();
+	world.load_seeded_battles(&args[1], seed);
+	if !world.battleList.contains_key(&args[2]) || !world.battleList.contains_key(&args[3]) {
+		print!("Battle \"{}\" or \"{}\" doesn't exist in map \"{}\".\n", args[2], args[3], args[1]);
+		return;
+	}
+	let playerTeam = get_team(&world.battleList[&args[2]], difficulty);
+	let enemyBattle = world.battleList[&args[3]].clone();
+
+	//* Simulate */
+	let mut summary = Summary {
+		wins: 0,
+		losses: 0,
+		unfinished: 0,
+
+		rounds: 0,
+		actions: 0,
+		hits: Vec::new(),
+		damage: [0,0],
+	};
+	for i in 0..count {
+		let mut battleData = BattleData::init();
+		battleData.silent = true;
+		battleData.animated = false;
+		battleData.difficulty = difficulty;
+		battleData.start_seeded_battle(enemyBattle.clone(), &playerTeam, seed.wrapping_add(i as u64));
+
+		let mut actions = 0;
+		while battleData.outcome.is_none() && actions < MAX_ACTIONS {
+			let action = battle::ai::choose_action(&battleData, difficulty, &mut battleData.aiRng.borrow_mut());
+			if !battleData.apply_action(&action) { battleData.apply_action(&BattleAction::Pass); }
+			actions += 1;
+		}
+
+		match battleData.outcome {
+			Some(BattleOutcome::Win)	=> summary.wins += 1,
+			Some(BattleOutcome::Loss)	=> summary.losses += 1,
+			_							=> summary.unfinished += 1,
+		}
+		summary.rounds += battleData.roundTotal;
+		summary.actions += actions;
+		for result in battleData.results.iter() {
+			if result.missed || result.damage <= 0 { continue; }
+			summary.hits.push(result.damage);
+			if result.attacker.starts_with("player") { summary.damage[0] += result.damage; }
+			else { summary.damage[1] += result.damage; }
+		}
+	}
+
+	print_summary(&summary, count, &args[2], &args[3], seed, difficulty);
+}
+
+
+//= Procedures
+
+/// Returns the team the trainer of the input battle uses on the difficulty.
+/// <br>Both teams of a double battle are combined, up to the team size.
+fn get_team(battle: &BattleType, difficulty: Difficulty) -> MonsterTeam {
+	let mut output = MonsterTeam::new();
+
+	match battle {
+		BattleType::Single { easyTeam, mediumTeam, hardTeam, .. } => {
+			match difficulty {
+				Difficulty::Easy	=> output = easyTeam.clone(),
+				Difficulty::Medium	=> output = mediumTeam.clone(),
+				Difficulty::Hard	=> output = hardTeam.clone(),
+			}
+		}
+		BattleType::Double { easyTeam, mediumTeam, hardTeam, .. } => {
+			let teams = match difficulty {
+				Difficulty::Easy	=> easyTeam,
+				Difficulty::Medium	=> mediumTeam,
+				Difficulty::Hard	=> hardTeam,
+			};
+			//* Alternate so both trainers' leads are sent out first */
+			for i in 0..4 {
+				for team in teams.iter() {
+					if team.0[i].is_some() { output.add_member(team.0[i].clone().unwrap()); }
+				}
+			}
+		}
+		BattleType::Wild { monster, .. } => { output.add_member(monster.clone()); }
+		BattleType::Empty => {}
+	}
+
+	return output;
+}
+
+/// Prints the results of the simulation.
+fn print_summary(summary: &Summary, count: i32, player: &str, enemy: &str, seed: u64, difficulty: Difficulty) {
+	let total = count.max(1) as f32;
+
+	print!("{} vs {}, {} battles on {} (seeds {} to {})\n", player, enemy, count, difficulty, seed, seed.wrapping_add(count.max(1) as u64 - 1));
+	print!("Wins:        {} ({:.1}%)\n", summary.wins, summary.wins as f32 * 100.0 / total);
+	print!("Losses:      {} ({:.1}%)\n", summary.losses, summary.losses as f32 * 100.0 / total);
+	print!("Unfinished:  {}\n", summary.unfinished);
+	print!("Avg rounds:  {:.2}\n", summary.rounds as f32 / total);
+	print!("Avg turns:   {:.2}\n", summary.actions as f32 / total);
+	print!("Avg damage:  {:.1} dealt, {:.1} taken per battle\n", summary.damage[0] as f32 / total, summary.damage[1] as f32 / total);
+
+	if summary.hits.len() == 0 {
+		print!("Damage:      no hits landed\n");
+		return;
+	}
+	let hits = summary.hits.len() as f32;
+	let mean = summary.hits.iter().sum::<i32>() as f32 / hits;
+	let deviation = (summary.hits.iter().map(|d| (*d as f32 - mean).powi(2)).sum::<f32>() / hits).sqrt();
+	print!(
+		"Damage:      {} hits, min {}, max {}, mean {:.2}, deviation {:.2}\n",
+		summary.hits.len(),
+		summary.hits.iter().min().unwrap(),
+		summary.hits.iter().max().unwrap(),
+		mean,
+		deviation,
+	);
+}
@@ -7,7 +7,8 @@
 
 //= Imports
 use std::collections::HashMap;
-use crate::{settings, camera::Camera, player::Player, world::World, graphics::Graphics, audio::Audio, localization, events::event_handler::EventHandler, battle::BattleData};
+use once_cell::sync::Lazy;
+use crate::{utilities::debug, monsters::{attacks::{self, Attack}, abilities::{self, Ability}, species::{self, Species}, types::{self, TypeChart}}, settings, camera::Camera, player::Player, world::World, graphics::Graphics, audio::Audio, localization, events::event_handler::EventHandler, battle::{BattleData, arena::{self, BattleTile}}};
 
 
 //= Structs
@@ -47,8 +48,21 @@ pub static mut SETTINGS : settings::Settings = settings::Settings{
 	sfxVolume:		1.0,
 
 	difficulty:		settings::Difficulty::Medium,
+	recordReplays:	false,
+	saveBattleLogs:	false,
 };
 
+/// Attack definitions indexed by their ID.
+pub static ATTACKS : Lazy<HashMap<String, Attack>> = Lazy::new(|| attacks::load());
+/// Ability definitions indexed by their ID.
+pub static ABILITIES : Lazy<HashMap<String, Ability>> = Lazy::new(|| abilities::load());
+/// Species definitions indexed by their ID.
+pub static SPECIES : Lazy<HashMap<String, Species>> = Lazy::new(|| species::load());
+/// Type effectiveness multipliers.
+pub static TYPE_CHART : Lazy<TypeChart> = Lazy::new(|| types::load());
+/// Battle arena layouts indexed by their ID.
+pub static ARENAS : Lazy<HashMap<String, HashMap<[i32;3], BattleTile>>> = Lazy::new(|| arena::load());
+
 
 //= Procedures
 
@@ -112,4 +126,35 @@ pub fn get_sfx_volume() -> f32 {
 }
 pub fn get_difficulty() -> settings::Difficulty {
 	unsafe { return SETTINGS.difficulty }
-}
\ No newline at end of file
+}
+pub fn get_record_replays() -> bool {
+	unsafe { return SETTINGS.recordReplays }
+}
+pub fn get_save_battle_logs() -> bool {
+	unsafe { return SETTINGS.saveBattleLogs }
+}
+
+/// Attack definition calls
+pub fn get_attack( id: &str ) -> &'static Attack {
+	if !ATTACKS.contains_key(id) {
+		debug::log(&format!("[ERROR] - Attack \"{}\" doesn't exist.\n", id));
+		return &ATTACKS[""];
+	}
+	return &ATTACKS[id];
+}
+/// Ability definition calls
+pub fn get_ability( id: &str ) -> &'static Ability {
+	if !ABILITIES.contains_key(id) {
+		debug::log(&format!("[ERROR] - Ability \"{}\" doesn't exist.\n", id));
+		return &ABILITIES[""];
+	}
+	return &ABILITIES[id];
+}
+/// Species definition calls
+pub fn get_species( id: &str ) -> &'static Species {
+	if !SPECIES.contains_key(id) {
+		debug::log(&format!("[ERROR] - Species \"{}\" doesn't exist.\n", id));
+		return &SPECIES[""];
+	}
+	return &SPECIES[id];
+}
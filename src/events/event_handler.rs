@@ -12,6 +12,7 @@
 use std::collections::HashMap;
 use super::conditionals::Condition;
 use super::{textbox, animation};
+use crate::monsters::{MonsterAttacks, LevelUpResult};
 
 
 
@@ -25,6 +26,17 @@ pub struct EventHandler{
 	pub textbox: textbox::Textbox,
 
 	pub eventVariables: HashMap<String, Condition>,
+	/// Temporary strings substituted into textboxes.
+	pub textVariables: HashMap<String, String>,
+
+	/// Attacks waiting to be learned and the team position of the monster learning them.
+	pub learnQueue: Vec<(usize, MonsterAttacks)>,
+	pub learnStage: i32,
+
+	/// Levels gained by each team member since their stats were last shown.
+	pub levelUps: [Option<LevelUpResult>;4],
+	/// Team position of the monster whose stats are being shown.
+	pub showStats: Option<usize>,
 
 	pub animation: Option<animation::Animation>,
 	pub emotes: Vec<animation::EmoteAnimation>,
@@ -50,6 +62,13 @@ impl EventHandler {
 			textbox: 		textbox::init(),
 	
 			eventVariables: HashMap::new(),
+			textVariables:	HashMap::new(),
+
+			learnQueue:		Vec::new(),
+			learnStage:		0,
+
+			levelUps:		[None, None, None, None],
+			showStats:		None,
 	
 			animation:		None,
 			emotes:			Vec::new(),
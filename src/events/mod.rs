@@ -12,7 +12,7 @@ pub mod textbox;
 pub mod animation;
 pub mod parser;
 
-use crate::{overworld::{Direction, self}, data, monsters, battle::{self, BattleType}, raylib::vectors::Vector3};
+use crate::{overworld::{Direction, self}, data, monsters, battle, raylib::vectors::Vector3};
 
 
 //= Enumerations
@@ -69,11 +69,17 @@ pub enum EventChain{
 		monsterPosition: usize,
 		amount: i32,
 	},
+	/// Teach player's monster an attack, asking to forget one if needed
+	LearnAttack{
+		monsterPosition: usize,
+		attack: monsters::MonsterAttacks,
+	},
 	/// Show level up stat changes for monster
-	//ShowStats{},
+	ShowStats{ monsterPosition: usize },
 
 	//= Battle
-	StartBattle{ battle: battle::BattleType },
+	/// Starts a battle, with optional events to start when it's won or lost.
+	StartBattle{ battle: battle::BattleType, winEvent: Option<String>, lossEvent: Option<String> },
 	EndBattle,
 
 	//= Camera controls
@@ -205,6 +211,12 @@ impl Event {
 				EventChain::GiveExperience { monsterPosition, amount } => {
 					str += &format!("GIVE_EXPERIENCE-[{}->{}]\n",amount,monsterPosition);
 				}
+				EventChain::LearnAttack { monsterPosition, attack } => {
+					str += &format!("LEARN_ATTACK-[{}->{}]\n",attack,monsterPosition);
+				}
+				EventChain::ShowStats { monsterPosition } => {
+					str += &format!("SHOW_STATS-{}\n",monsterPosition);
+				}
 				EventChain::StartBattle { .. } => {
 					// TODO
 					str += &format!("STARTBATTLE-\n");
@@ -349,30 +361,81 @@ pub fn parse_event( gamestate : &mut data::Gamestate ) -> bool {
 
 		//= Monster events
 		EventChain::GiveMonster { monster } => {
-				gamestate.player.monsters.add_member(monster.clone());
+				gamestate.player.give_monster(monster.clone());
 				gamestate.eventHandler.currentChain += 1;
 			}
 		EventChain::GiveExperience { monsterPosition, amount } => {
+				//* Finish learning attacks before continuing */
+				if !learn_attacks(gamestate) { return true; }
+
 				gamestate.audio.play_sound("experience".to_string());
 				gamestate.eventHandler.internal += 1;
-				if gamestate.eventHandler.internal < *amount {
-					gamestate.player.monsters.0[*monsterPosition].as_mut().unwrap().give_experience(1);
+				if gamestate.eventHandler.internal <= *amount {
+					let result = gamestate.player.monsters.0[*monsterPosition].as_mut().unwrap().give_experience(1);
+					if result.is_some() {
+						let result = result.unwrap();
+						gamestate.audio.play_sound("level_up".to_string());
+						for attack in result.newAttacks.iter() { gamestate.eventHandler.learnQueue.push((*monsterPosition, attack.clone())); }
+
+						//* Keep track of all levels gained for ShowStats */
+						match gamestate.eventHandler.levelUps[*monsterPosition].as_mut() {
+							Some(levelUp)	=> levelUp.merge(result),
+							None			=> gamestate.eventHandler.levelUps[*monsterPosition] = Some(result),
+						}
+					}
 				} else {
 					gamestate.eventHandler.currentChain += 1;
 					gamestate.eventHandler.internal = 0;
 				}
 			}
 
+		EventChain::LearnAttack { monsterPosition, attack } => {
+				if gamestate.eventHandler.internal == 0 {
+					gamestate.eventHandler.learnQueue.push((*monsterPosition, attack.clone()));
+					gamestate.eventHandler.internal = 1;
+				}
+				if learn_attacks(gamestate) {
+					gamestate.eventHandler.currentChain += 1;
+					gamestate.eventHandler.internal = 0;
+				}
+			}
+
+		EventChain::ShowStats { monsterPosition } => {
+				//* Skip if the monster hasn't leveled up */
+				if gamestate.eventHandler.levelUps[*monsterPosition].is_none() || gamestate.player.monsters.0[*monsterPosition].is_none() {
+					gamestate.eventHandler.currentChain += 1;
+					return true;
+				}
+
+				if gamestate.eventHandler.showStats.is_none() {
+					gamestate.eventHandler.showStats = Some(*monsterPosition);
+
+					let name = gamestate.player.monsters.0[*monsterPosition].as_ref().unwrap().get_name();
+					let monsterName: String;
+					if name.1 { monsterName = name.0; }
+					else { monsterName = gamestate.localization[&name.0].to_string(); }
+					let level = gamestate.eventHandler.levelUps[*monsterPosition].as_ref().unwrap().levelAfter;
+					gamestate.eventHandler.textVariables.insert("MONSTER_0_NAME".to_string(), monsterName);
+					gamestate.eventHandler.textVariables.insert("MONSTER_0_LEVEL".to_string(), level.to_string());
+				}
+				if textbox::run(gamestate, "level_up".to_string()) {
+					gamestate.eventHandler.showStats = None;
+					gamestate.eventHandler.levelUps[*monsterPosition] = None;
+					gamestate.eventHandler.currentChain += 1;
+				}
+			}
+
 		//= Battle events
-		EventChain::StartBattle { battle } => {
+		EventChain::StartBattle { battle, winEvent, lossEvent } => {
 			gamestate.player.canMove = false;
 			gamestate.battleData.start_battle(battle.clone(), &gamestate.player.monsters);
+			gamestate.battleData.winEvent = winEvent.clone();
+			gamestate.battleData.lossEvent = lossEvent.clone();
 			gamestate.eventHandler.currentChain += 1;
 		}
 		EventChain::EndBattle => {
-			gamestate.player.canMove = true;
-			gamestate.battleData.start_battle(BattleType::Empty, &gamestate.player.monsters);
-			gamestate.eventHandler.currentChain += 1;
+			//* The win or loss event starts from it's first step */
+			if !battle::end_battle(gamestate) { gamestate.eventHandler.currentChain += 1; }
 		}
 
 		//= Camera events
@@ -484,4 +547,79 @@ pub fn clear_event( gamestate : &mut data::Gamestate ) {
 	gamestate.eventHandler.currentEvent = "".to_string();
 	gamestate.eventHandler.internal = 0;
 	gamestate.player.canMove = true;
-}
\ No newline at end of file
+}
+
+/// Teaches the first attack in the learn queue, asking the player to forget an attack if there's no room.
+/// <br>Returns true once the queue is empty.
+pub fn learn_attacks( gamestate : &mut data::Gamestate ) -> bool {
+	if gamestate.eventHandler.learnQueue.len() == 0 { return true; }
+
+	let (position, attack) = gamestate.eventHandler.learnQueue[0].clone();
+	if gamestate.player.monsters.0[position].is_none() {
+		gamestate.eventHandler.learnQueue.remove(0);
+		gamestate.eventHandler.learnStage = 0;
+		return false;
+	}
+
+	match gamestate.eventHandler.learnStage {
+		//* Attempt to learn */
+		0 => {
+			let name = gamestate.player.monsters.0[position].as_ref().unwrap().get_name();
+			let monsterName: String;
+			if name.1 { monsterName = name.0; }
+			else { monsterName = gamestate.localization[&name.0].to_string(); }
+			gamestate.eventHandler.textVariables.insert("MONSTER_NAME".to_string(), monsterName);
+			gamestate.eventHandler.textVariables.insert("ATTACK_NAME".to_string(), gamestate.localization[&attack.to_string()].to_string());
+
+			if gamestate.player.monsters.0[position].as_mut().unwrap().learn_attack(attack) { gamestate.eventHandler.learnStage = 1; }
+			else { gamestate.eventHandler.learnStage = 2; }
+		}
+		//* Learned */
+		1 => {
+			if textbox::run(gamestate, "learn_attack_learned".to_string()) {
+				gamestate.eventHandler.textbox.reset();
+				gamestate.eventHandler.learnQueue.remove(0);
+				gamestate.eventHandler.learnStage = 0;
+			}
+		}
+		//* Ask to forget an attack */
+		2 => {
+			if !gamestate.eventHandler.textbox.hasChoice {
+				gamestate.eventHandler.textbox.hasChoice = true;
+				for text in ["yes", "no", "", ""] {
+					gamestate.eventHandler.textbox.choiceList.push(textbox::Choice{text: text.to_string(), event: "".to_string(), position: -1});
+				}
+			}
+			if textbox::run(gamestate, "learn_attack_forget".to_string()) {
+				gamestate.eventHandler.textbox.reset();
+				if gamestate.eventHandler.textbox.lastChoice == 0 { gamestate.eventHandler.learnStage = 3; }
+				else { gamestate.eventHandler.learnStage = 4; }
+			}
+		}
+		//* Choose attack to forget */
+		3 => {
+			if !gamestate.eventHandler.textbox.hasChoice {
+				gamestate.eventHandler.textbox.hasChoice = true;
+				for known in gamestate.player.monsters.0[position].as_ref().unwrap().attacks.iter() {
+					gamestate.eventHandler.textbox.choiceList.push(textbox::Choice{text: known.to_string(), event: "".to_string(), position: -1});
+				}
+			}
+			if textbox::run(gamestate, "learn_attack_which".to_string()) {
+				gamestate.eventHandler.textbox.reset();
+				let slot = gamestate.eventHandler.textbox.lastChoice as usize;
+				gamestate.player.monsters.0[position].as_mut().unwrap().attacks[slot] = attack;
+				gamestate.eventHandler.learnStage = 1;
+			}
+		}
+		//* Didn't learn */
+		_ => {
+			if textbox::run(gamestate, "learn_attack_skipped".to_string()) {
+				gamestate.eventHandler.textbox.reset();
+				gamestate.eventHandler.learnQueue.remove(0);
+				gamestate.eventHandler.learnStage = 0;
+			}
+		}
+	}
+
+	return false;
+}
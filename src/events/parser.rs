@@ -104,6 +104,8 @@ pub fn parse_value( world: &mut world::World, value: &Value ) -> EventChain {
 						monster: monsters::Monster::new(
 							monsters::MonsterSpecies::from_str(value.as_array().unwrap()[1].as_array().unwrap()[1].as_str().unwrap()).unwrap(),
 							value.as_array().unwrap()[1].as_array().unwrap()[2].as_i64().unwrap() as i32,
+							monsters::natures::MonsterNature::from_str(value.as_array().unwrap()[1].as_array().unwrap().get(3).and_then(|n| n.as_str()).unwrap_or("")).ok(),
+							None,
 						),
 					}
 				}
@@ -116,10 +118,32 @@ pub fn parse_value( world: &mut world::World, value: &Value ) -> EventChain {
 			}
 		}
 
+		"show_stats" => {
+			return EventChain::ShowStats { monsterPosition: value.as_array().unwrap()[1].as_i64().unwrap() as usize }
+		}
+		"learn_attack" => {
+			let attack = monsters::MonsterAttacks::from_str(value.as_array().unwrap()[2].as_str().unwrap());
+			if attack.is_err() {
+				debug::log("[ERROR] - Attempted to teach an attack that doesn't exist.\n");
+				return EventChain::Test { text: value.as_array().unwrap()[0].as_str().unwrap().to_string() };
+			}
+			return EventChain::LearnAttack {
+				monsterPosition:	value.as_array().unwrap()[1].as_i64().unwrap() as usize,
+				attack:				attack.unwrap(),
+			}
+		}
+
 		//= Battle Events
 		"start_battle" => {
+			let event = |index: usize| -> Option<String> {
+				let id = value.as_array().unwrap().get(index).and_then(|v| v.as_str()).unwrap_or("");
+				if id == "" { return None; }
+				return Some(id.to_string());
+			};
 			return EventChain::StartBattle {
 				battle: world.battleList[&value.as_array().unwrap()[1].as_str().unwrap().to_string()].clone(),
+				winEvent: event(2),
+				lossEvent: event(3),
 			}
 		}
 		"end_battle" => {
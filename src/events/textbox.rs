@@ -47,6 +47,8 @@ pub struct Textbox{
 	pub hasChoice: bool,
 	pub choiceList: Vec<Choice>,
 	pub curPosition: i32,
+	/// The last choice confirmed. Kept through resets.
+	pub lastChoice: i32,
 
 	pub isInput: bool,
 	pub input: String,
@@ -102,6 +104,7 @@ pub fn init() -> Textbox {
 		hasChoice: false,
 		choiceList: Vec::new(),
 		curPosition: 0,
+		lastChoice: 0,
 
 		isInput: false,
 		input: "".to_string(),
@@ -132,6 +135,9 @@ pub fn run( gamestate : &mut data::Gamestate, text : String ) -> bool {
 			str = str.replace("{PLAYER_PRO_POSSESIVE}", &gamestate.eventHandler.playerPronouns[2]);
 			str = str.replace("{RIVAL_NAME}", &gamestate.eventHandler.rivalName);
 			str = str.replace("{PLAYER_PRO_SUBJECT}", &gamestate.eventHandler.playerPronouns[0]);
+			for (variable, value) in &gamestate.eventHandler.textVariables {
+				str = str.replace(&("{".to_string() + variable + "}"), value);
+			}
 			//for (variable, cond) in &gamestate.eventHandler.eventVariables {
 			//	let varStr = "{".to_string() + &variable.to_string() + "}";
 			//	str = str.replace(&varStr, &cond.to_string());
@@ -197,6 +203,7 @@ pub fn run( gamestate : &mut data::Gamestate, text : String ) -> bool {
 					if gamestate.eventHandler.textbox.hasChoice {
 						gamestate.audio.play_sound("button".to_string());
 
+						gamestate.eventHandler.textbox.lastChoice = gamestate.eventHandler.textbox.curPosition;
 						let choice = &gamestate.eventHandler.textbox.choiceList[gamestate.eventHandler.textbox.curPosition as usize];
 						if choice.event == "" {
 							if choice.position != -1 { gamestate.eventHandler.currentChain = choice.position; }
@@ -375,4 +382,54 @@ pub fn draw( gamestate : &mut data::Gamestate ) {
 			);
 		}
 	}
-}
\ No newline at end of file
+
+	draw_stats(gamestate);
+}
+
+/// Draw the stat changes of a monster that leveled up
+pub fn draw_stats( gamestate : &mut data::Gamestate ) {
+	if gamestate.eventHandler.showStats.is_none() { return; }
+	let position = gamestate.eventHandler.showStats.unwrap();
+	if gamestate.eventHandler.levelUps[position].is_none() { return; }
+	let levelUp = gamestate.eventHandler.levelUps[position].as_ref().unwrap();
+
+	let ratio = data::get_screenratio();
+	let fontSize = 24.0 * ratio;
+	let panelWidth = 400.0 * ratio;
+	let panelHeight = 330.0 * ratio;
+	let panelOffsetX = data::get_screenwidth() as f32 - panelWidth - (24.0 * ratio);
+	let panelOffsetY = data::get_screenheight() as f32 - (240.0 * ratio) - panelHeight;
+
+	gamestate.graphics.textures["ui_textbox_general"].draw_npatch(
+		Rectangle {
+			x:		panelOffsetX,
+			y:		panelOffsetY,
+			width:	panelWidth,
+			height:	panelHeight,
+		},
+		0.0,
+	);
+
+	let names = ["stat_health", "stat_physical_attack", "stat_physical_defense", "stat_special_attack", "stat_special_defense", "stat_speed"];
+	let before = levelUp.before.to_array();
+	let after = levelUp.after.to_array();
+	for i in 0..6 {
+		let y = panelOffsetY + (fontSize * 2.0) + ((fontSize * 1.5) * i as f32);
+		gamestate.graphics.fonts["default"].draw_pro(
+			&gamestate.localization[names[i]],
+			Vector2 { x: panelOffsetX + (fontSize * 1.5), y },
+			0.0,
+			fontSize,
+			5.0 * ratio,
+			raylib_ffi::Color{r:57,g:57,b:57,a:255},
+		);
+		gamestate.graphics.fonts["default"].draw_pro(
+			&format!("{} +{}", after[i], after[i] - before[i]),
+			Vector2 { x: panelOffsetX + (panelWidth * 0.65), y },
+			0.0,
+			fontSize,
+			5.0 * ratio,
+			raylib_ffi::Color{r:57,g:57,b:57,a:255},
+		);
+	}
+}
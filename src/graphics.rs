@@ -101,6 +101,8 @@ impl Graphics {
 			let subimg = img.from_image(Rectangle { x: (32 * i) as f32, y: 0.0, width: 32.0, height: 32.0 });
 			self.textures.insert("mon_158_".to_string() + &i.to_string(), subimg.load_texture());
 		}
+		//* Attack projectile, tinted by type */
+		self.textures.insert("battle_projectile".to_string(), Texture::load("data/sprites/battle/projectile.png"));
 
 		//* UI */
 		//* Textbox */
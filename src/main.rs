@@ -31,6 +31,16 @@ fn main() {
 	gamestate.worldData.load_all("newbark");
 	gamestate.audio.play_music("new_bark_town".to_string());
 
+	//* Replay viewer */
+	let args: Vec<String> = std::env::args().collect();
+	if args.len() > 2 && args[1] == "--replay" {
+		let replay = battle::replay::Replay::load(&args[2]);
+		if replay.is_some() {
+			gamestate.player.canMove = false;
+			gamestate.battleData.start_replay(replay.unwrap());
+		}
+	}
+
 	while !raylib::window_should_close() && gamestate.running {
 		//* Update */
 		gamestate.camera.update(
@@ -41,7 +51,8 @@ fn main() {
 		player::controls(&mut gamestate);
 		gamestate.worldData.update();
 		gamestate.audio.update();
-		if gamestate.battleData.started { gamestate.battleData.update(); }
+		if gamestate.battleData.started { gamestate.battleData.update(&mut gamestate.audio); }
+		if gamestate.battleData.outcome.is_some() && gamestate.battleData.animations.is_empty() { battle::end_battle(&mut gamestate); }
 
 		raylib::begin_drawing();
 		{
@@ -0,0 +1,217 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{collections::HashMap, fs::read_to_string, str::FromStr};
+
+use crate::utilities::debug;
+use super::{AttackCategory, MonsterTypes, BattleStat, attacks::{self, AttackCondition}};
+
+
+//= Enumerations
+
+/// Who the effect of an ability is applied to.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AbilityTarget {
+	/// The monster with the ability.
+	User,
+	/// The monster that hit the user, only for ``on_hit``.
+	Attacker,
+	/// Every opposing monster on the field.
+	Enemies,
+}
+impl FromStr for AbilityTarget {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"user"		=> Ok(AbilityTarget::User),
+			"attacker"	=> Ok(AbilityTarget::Attacker),
+			"enemies"	=> Ok(AbilityTarget::Enemies),
+			_			=> Err(()),
+		}
+	}
+}
+
+
+//= Structures
+
+/// Something an ability does when one of it's hooks is triggered.
+#[derive(Clone)]
+pub struct AbilityEffect {
+	pub target: AbilityTarget,
+	/// Only triggers for attacks of this category, for ``on_hit``.
+	pub category: Option<AttackCategory>,
+
+	pub condition: Option<AttackCondition>,
+	/// Stages added to the stats of the targets.
+	pub statChanges: Vec<(BattleStat, i8)>,
+}
+
+/// Raises the power of attacks of a type while the user's health is low.
+#[derive(Clone, Copy)]
+pub struct TypeBoost {
+	pub attackType: MonsterTypes,
+	/// Fraction of max health at or below which the boost applies.
+	pub threshold: f32,
+	pub multiplier: f32,
+}
+
+/// The definition of an ability.
+/// <br>The battle calls each hook at a fixed point, see the ``trigger_`` procedures in ``battle::engine``.
+#[derive(Clone)]
+pub struct Ability {
+	pub id: String,
+	/// Localization key
+	pub name: String,
+
+	/// When the user is placed on the field or switched in.
+	pub onEnter: Option<AbilityEffect>,
+	/// When the user is hit by an attack and doesn't faint.
+	pub onHit: Option<AbilityEffect>,
+	/// At the end of each of the user's turns.
+	pub onTurnEnd: Option<AbilityEffect>,
+
+	pub typeBoost: Option<TypeBoost>,
+	/// Multipliers applied to the user's stats on top of their stages.
+	pub statModifiers: Vec<(BattleStat, f32)>,
+}
+
+
+//= Procedures
+
+impl Ability {
+
+	/// Creates the definition used for monsters without an ability.
+	pub fn empty() -> Self {
+		return Ability {
+			id: "".to_string(),
+			name: "".to_string(),
+
+			onEnter: None,
+			onHit: None,
+			onTurnEnd: None,
+
+			typeBoost: None,
+			statModifiers: Vec::new(),
+		}
+	}
+
+	/// Returns the damage multiplier for an attack of the input type used by a monster with the input health.
+	pub fn attack_multiplier(&self, attackType: MonsterTypes, hpCur: i32, hpMax: i32) -> f32 {
+		if self.typeBoost.is_none() { return 1.0; }
+		let boost = self.typeBoost.unwrap();
+
+		if boost.attackType == attackType && hpCur as f32 <= hpMax as f32 * boost.threshold { return boost.multiplier; }
+		return 1.0;
+	}
+
+	/// Returns the multiplier the ability applies to the input stat.
+	pub fn stat_multiplier(&self, stat: BattleStat) -> f32 {
+		let mut output = 1.0;
+		for (modified, multiplier) in self.statModifiers.iter() {
+			if *modified == stat { output *= multiplier; }
+		}
+		return output;
+	}
+
+}
+
+/// Loads all ability definitions into a Hashmap indexed by their ID.
+pub fn load() -> HashMap<String, Ability> {
+	let mut output: HashMap<String, Ability> = HashMap::new();
+	output.insert("".to_string(), Ability::empty());
+
+	//* Attempt to load abilities file */
+	let fileResult = read_to_string("data/monsters/abilities.json");
+	if fileResult.is_err() {
+		debug::log("[ERROR] - Failed to load abilities file.\n");
+		return output;
+	}
+
+	//* Convert to JSON and read */
+	let jsonFile: serde_json::Value = serde_json::from_str(&fileResult.unwrap()).unwrap();
+	for i in jsonFile["abilities"].as_array().unwrap() {
+		let id = i["id"].as_str().unwrap().to_string();
+
+		let mut hooks: [Option<AbilityEffect>;3] = [None, None, None];
+		let mut valid = true;
+		for (index, key) in ["on_enter", "on_hit", "on_turn_end"].iter().enumerate() {
+			if i[key].is_null() { continue; }
+			hooks[index] = parse_effect(&i[key]);
+			if hooks[index].is_none() { valid = false; }
+		}
+
+		let mut typeBoost = None;
+		if !i["type_boost"].is_null() {
+			let attackType = MonsterTypes::from_str(i["type_boost"]["type"].as_str().unwrap_or(""));
+			if attackType.is_err() { valid = false; }
+			else {
+				typeBoost = Some(TypeBoost {
+					attackType: attackType.unwrap(),
+					threshold: i["type_boost"]["threshold"].as_f64().unwrap_or(1.0 / 3.0) as f32,
+					multiplier: i["type_boost"]["multiplier"].as_f64().unwrap_or(1.5) as f32,
+				});
+			}
+		}
+
+		let mut statModifiers: Vec<(BattleStat, f32)> = Vec::new();
+		for o in i["stat_modifiers"].as_array().unwrap_or(&Vec::new()) {
+			let stat = BattleStat::from_str(o[0].as_str().unwrap_or(""));
+			if stat.is_err() {
+				valid = false;
+				continue;
+			}
+			statModifiers.push((stat.unwrap(), o[1].as_f64().unwrap_or(1.0) as f32));
+		}
+
+		if !valid {
+			debug::log(&format!("[ERROR] - Ability \"{}\" has an invalid definition.\n", id));
+			continue;
+		}
+
+		let [onEnter, onHit, onTurnEnd] = hooks;
+		output.insert(
+			id.to_string(),
+			Ability {
+				id,
+				name: i["name"].as_str().unwrap().to_string(),
+
+				onEnter,
+				onHit,
+				onTurnEnd,
+
+				typeBoost,
+				statModifiers,
+			},
+		);
+	}
+
+	return output;
+}
+
+/// Converts input JSON value into an ability effect.
+fn parse_effect(value: &serde_json::Value) -> Option<AbilityEffect> {
+	let target = AbilityTarget::from_str(value["target"].as_str().unwrap_or("user")).ok()?;
+
+	let mut category = None;
+	if !value["category"].is_null() { category = Some(AttackCategory::from_str(value["category"].as_str().unwrap_or("")).ok()?); }
+
+	let mut condition = None;
+	if !value["condition"].is_null() { condition = Some(attacks::parse_condition(&value["condition"])?); }
+
+	let mut statChanges: Vec<(BattleStat, i8)> = Vec::new();
+	for o in value["stat_changes"].as_array().unwrap_or(&Vec::new()) {
+		let stat = BattleStat::from_str(o[0].as_str().unwrap_or("")).ok()?;
+		statChanges.push((stat, o[1].as_i64().unwrap_or(0) as i8));
+	}
+
+	return Some(AbilityEffect {
+		target,
+		category,
+
+		condition,
+		statChanges,
+	});
+}
@@ -0,0 +1,327 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{collections::HashMap, fs::read_to_string, str::FromStr};
+
+use crate::utilities::debug;
+use super::{AttackCategory, MonsterTypes, MonsterConditions, BattleStat};
+
+
+//= Constants
+/// Critical hit chance of attacks that don't declare one.
+pub const DEFAULT_CRIT_RATE: f32 = 0.0625;
+
+
+//= Enumerations
+
+/// Where the user of an attack has to stand on their half of the field.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AttackPosition {
+	Any,
+	/// The four columns closest to the enemy.
+	Front,
+	/// The four columns furthest from the enemy.
+	Back,
+}
+impl FromStr for AttackPosition {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"any"	=> Ok(AttackPosition::Any),
+			"front"	=> Ok(AttackPosition::Front),
+			"back"	=> Ok(AttackPosition::Back),
+			_		=> Err(()),
+		}
+	}
+}
+
+/// What the area of an attack is positioned relative to.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AreaAnchor {
+	/// The entire opposing half.
+	Field,
+	/// Fixed columns, rows relative to the user.
+	User,
+	/// Columns and rows relative to the targeting cursor.
+	Cursor,
+}
+impl FromStr for AreaAnchor {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"field"		=> Ok(AreaAnchor::Field),
+			"user"		=> Ok(AreaAnchor::User),
+			"cursor"	=> Ok(AreaAnchor::Cursor),
+			_			=> Err(()),
+		}
+	}
+}
+
+
+/// How an attack is animated in battle.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AttackAnimation {
+	/// Only plays the sound.
+	None,
+	/// The user leans towards the target and back.
+	Lunge,
+	/// A projectile flies from the user to the target.
+	Projectile,
+}
+impl FromStr for AttackAnimation {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"none"			=> Ok(AttackAnimation::None),
+			"lunge"			=> Ok(AttackAnimation::Lunge),
+			"projectile"	=> Ok(AttackAnimation::Projectile),
+			_				=> Err(()),
+		}
+	}
+}
+
+
+//= Structures
+
+/// The shape of an attack on the opposing 8x8 half of the field.
+/// <br>Columns count from ``0`` at the center line to ``7`` at the far edge.
+#[derive(Clone)]
+pub struct AttackArea {
+	pub anchor: AreaAnchor,
+	/// Columns covered by ``Field`` and ``User`` areas, or column offsets from the cursor.
+	pub x: [i32;2],
+	/// Row offsets from the user or cursor.
+	pub z: [i32;2],
+	/// Columns the cursor is allowed to be in.
+	pub bounds: [i32;2],
+}
+
+/// A condition an attack can inflict on the monsters it hits.
+#[derive(Clone)]
+pub struct AttackCondition {
+	pub condition: MonsterConditions,
+	/// Chance out of ``1.0`` for each target.
+	pub chance: f32,
+}
+
+/// The definition of an attack.
+#[derive(Clone)]
+pub struct Attack {
+	pub id: String,
+	/// Localization key
+	pub name: String,
+
+	pub attackType: MonsterTypes,
+	pub category: AttackCategory,
+	pub power: i32,
+	/// Turn order bracket the user acts in for the next round.
+	pub priority: i32,
+	/// Chance out of ``1.0`` to hit each target.
+	pub accuracy: f32,
+	/// Chance out of ``1.0`` to land a critical hit on each target.
+	pub critRate: f32,
+
+	pub position: AttackPosition,
+	pub area: AttackArea,
+	/// Area used instead when the user is in the back columns.
+	pub areaBack: Option<AttackArea>,
+
+	pub condition: Option<AttackCondition>,
+	/// Stages added to the stats of the monsters hit.
+	pub statChanges: Vec<(BattleStat, i8)>,
+
+	pub animation: AttackAnimation,
+	pub sound: String,
+}
+
+
+//= Procedures
+
+impl AttackArea {
+
+	/// Checks whether the area covers the input tile.
+	/// <br>All positions are in local columns and rows, see ``AttackArea``.
+	pub fn covers(&self, user: [i32;2], cursor: [i32;2], tile: [i32;2]) -> bool {
+		if tile[0] < 0 || tile[0] > 7 { return false; }
+
+		match self.anchor {
+			AreaAnchor::Field => {
+				return tile[0] >= self.x[0] && tile[0] <= self.x[1];
+			}
+			AreaAnchor::User => {
+				let offset = tile[1] - user[1];
+				return tile[0] >= self.x[0] && tile[0] <= self.x[1] && offset >= self.z[0] && offset <= self.z[1];
+			}
+			AreaAnchor::Cursor => {
+				if !self.cursor_in_bounds(cursor) { return false; }
+				let offset = [tile[0] - cursor[0], tile[1] - cursor[1]];
+				return offset[0] >= self.x[0] && offset[0] <= self.x[1] && offset[1] >= self.z[0] && offset[1] <= self.z[1];
+			}
+		}
+	}
+
+	/// Checks whether the input cursor column is one the area can be aimed at.
+	pub fn cursor_in_bounds(&self, cursor: [i32;2]) -> bool {
+		return cursor[0] >= self.bounds[0] && cursor[0] <= self.bounds[1];
+	}
+
+}
+
+impl Attack {
+
+	/// Creates the definition used for empty attack slots.
+	pub fn empty() -> Self {
+		return Attack {
+			id: "".to_string(),
+			name: "".to_string(),
+
+			attackType: MonsterTypes::None,
+			category: AttackCategory::Status,
+			power: 0,
+			priority: 0,
+			accuracy: 1.0,
+			critRate: 0.0,
+
+			position: AttackPosition::Any,
+			area: AttackArea { anchor: AreaAnchor::Field, x: [-1,-1], z: [0,0], bounds: [0,7] },
+			areaBack: None,
+
+			condition: None,
+			statChanges: Vec::new(),
+
+			animation: AttackAnimation::None,
+			sound: "".to_string(),
+		}
+	}
+
+	/// Checks whether the attack can be used from the input local column.
+	pub fn usable_from(&self, column: i32) -> bool {
+		match self.position {
+			AttackPosition::Any		=> return true,
+			AttackPosition::Front	=> return column > 3,
+			AttackPosition::Back	=> return column < 4,
+		}
+	}
+
+	/// Returns the area used from the input local column.
+	pub fn get_area(&self, column: i32) -> &AttackArea {
+		if column < 4 && self.areaBack.is_some() { return self.areaBack.as_ref().unwrap(); }
+		return &self.area;
+	}
+
+}
+
+/// Loads all attack definitions into a Hashmap indexed by their ID.
+pub fn load() -> HashMap<String, Attack> {
+	let mut output: HashMap<String, Attack> = HashMap::new();
+	output.insert("".to_string(), Attack::empty());
+
+	//* Attempt to load attacks file */
+	let fileResult = read_to_string("data/monsters/attacks.json");
+	if fileResult.is_err() {
+		debug::log("[ERROR] - Failed to load attacks file.\n");
+		return output;
+	}
+
+	//* Convert to JSON and read */
+	let jsonFile: serde_json::Value = serde_json::from_str(&fileResult.unwrap()).unwrap();
+	for i in jsonFile["attacks"].as_array().unwrap() {
+		let id = i["id"].as_str().unwrap().to_string();
+
+		let attackType = MonsterTypes::from_str(i["type"].as_str().unwrap());
+		let category = AttackCategory::from_str(i["category"].as_str().unwrap());
+		let position = AttackPosition::from_str(i["position"].as_str().unwrap());
+		let area = parse_area(&i["area"]);
+		let animation = AttackAnimation::from_str(i["animation"].as_str().unwrap_or("none"));
+		if attackType.is_err() || category.is_err() || position.is_err() || area.is_none() || animation.is_err() {
+			debug::log(&format!("[ERROR] - Attack \"{}\" has an invalid definition.\n", id));
+			continue;
+		}
+
+		let mut areaBack = None;
+		if !i["area_back"].is_null() { areaBack = parse_area(&i["area_back"]); }
+
+		let mut condition = None;
+		if !i["condition"].is_null() {
+			condition = parse_condition(&i["condition"]);
+			if condition.is_none() { debug::log(&format!("[ERROR] - Attack \"{}\" has an invalid condition.\n", id)); }
+		}
+
+		let mut statChanges: Vec<(BattleStat, i8)> = Vec::new();
+		if !i["stat_changes"].is_null() {
+			for o in i["stat_changes"].as_array().unwrap() {
+				let stat = BattleStat::from_str(o.as_array().unwrap()[0].as_str().unwrap());
+				if stat.is_err() {
+					debug::log(&format!("[ERROR] - Attack \"{}\" has an invalid stat change.\n", id));
+					continue;
+				}
+				statChanges.push((stat.unwrap(), o.as_array().unwrap()[1].as_i64().unwrap() as i8));
+			}
+		}
+
+		output.insert(
+			id.to_string(),
+			Attack {
+				id,
+				name: i["name"].as_str().unwrap().to_string(),
+
+				attackType: attackType.unwrap(),
+				category: category.unwrap(),
+				power: i["power"].as_i64().unwrap() as i32,
+				priority: i["priority"].as_i64().unwrap_or(0) as i32,
+				accuracy: i["accuracy"].as_f64().unwrap_or(1.0) as f32,
+				critRate: i["crit_rate"].as_f64().unwrap_or(DEFAULT_CRIT_RATE as f64) as f32,
+
+				position: position.unwrap(),
+				area: area.unwrap(),
+				areaBack,
+
+				condition,
+				statChanges,
+
+				animation: animation.unwrap(),
+				sound: i["sound"].as_str().unwrap_or("").to_string(),
+			},
+		);
+	}
+
+	return output;
+}
+
+/// Converts input JSON value into an attack area.
+fn parse_area(value: &serde_json::Value) -> Option<AttackArea> {
+	let anchor = AreaAnchor::from_str(value["anchor"].as_str().unwrap_or(""));
+	if anchor.is_err() { return None; }
+
+	let range = |key: &str, default: [i32;2]| -> [i32;2] {
+		if value[key].is_null() { return default; }
+		return [
+			value[key].as_array().unwrap()[0].as_i64().unwrap() as i32,
+			value[key].as_array().unwrap()[1].as_i64().unwrap() as i32,
+		];
+	};
+
+	return Some(AttackArea {
+		anchor: anchor.unwrap(),
+		x: range("x", [0,7]),
+		z: range("z", [0,0]),
+		bounds: range("bounds", [0,7]),
+	});
+}
+
+/// Converts input JSON value into an attack condition.
+pub fn parse_condition(value: &serde_json::Value) -> Option<AttackCondition> {
+	let stacks = value["stacks"].as_i64().unwrap_or(1) as i32;
+	let condition = match value["id"].as_str().unwrap_or("") {
+		"burned"	=> MonsterConditions::Burned { stacks },
+		_			=> return None,
+	};
+
+	return Some(AttackCondition {
+		condition,
+		chance: value["chance"].as_f64().unwrap_or(1.0) as f32,
+	});
+}
@@ -6,43 +6,22 @@
 
 
 //= Imports
+pub mod attacks;
+pub mod abilities;
+pub mod species;
+pub mod natures;
+pub mod types;
+
 use std::{fmt::Display, str::FromStr};
 
-use crate::utilities::debug;
+use crate::{utilities::{debug, random::Random}, data};
+use natures::{MonsterNature, NatureStat};
 
 
 //= Enumerations
 
-/// The monster's species.
-#[derive(Clone)]
-pub enum MonsterSpecies {
-	Mon152, //* Grass starter */
-	Mon155, //* Fire starter */
-	Mon158, //* Water starter */
-}
-impl Display for MonsterSpecies {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    	match self {
-			MonsterSpecies::Mon152 => return write!(f, "mon_152"),
-			MonsterSpecies::Mon155 => return write!(f, "mon_155"),
-			MonsterSpecies::Mon158 => return write!(f, "mon_158"),
-		}
-    }
-}
-impl FromStr for MonsterSpecies {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-			"mon_152" => Ok(MonsterSpecies::Mon152),
-			"mon_155" => Ok(MonsterSpecies::Mon155),
-			"mon_158" => Ok(MonsterSpecies::Mon158),
-			_ => Err(()),
-		}
-    }
-}
-
 /// The elemental typing of a monster.
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MonsterTypes {
 	None,
 	Normal,
@@ -61,47 +40,89 @@ impl Display for MonsterTypes {
 		}
     }
 }
+impl FromStr for MonsterTypes {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"none"		=> Ok(MonsterTypes::None),
+			"normal"	=> Ok(MonsterTypes::Normal),
+			"fire"		=> Ok(MonsterTypes::Fire),
+			"water"		=> Ok(MonsterTypes::Water),
+			"grass"		=> Ok(MonsterTypes::Grass),
+			_			=> Err(()),
+		}
+	}
+}
 
-/// The attacks a monster can use in battle.
-#[derive(Clone, PartialEq)]
-pub enum MonsterAttacks {
-	None,
-
-	Tackle,
-	Scratch,
-
-	Growl,
-	Leer,
-
-	Leafage,
-	Ember,
-	Aquajet,
+/// The damage category of an attack.
+#[derive(Clone, Copy, PartialEq)]
+pub enum AttackCategory {
+	Physical,
+	Special,
+	Status,
 }
-impl Display for MonsterAttacks {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-			MonsterAttacks::None	=> return write!(f, ""),
-			MonsterAttacks::Tackle	=> return write!(f, "tackle"),
-			MonsterAttacks::Scratch	=> return write!(f, "scratch"),
-			MonsterAttacks::Growl	=> return write!(f, "growl"),
-			MonsterAttacks::Leer	=> return write!(f, "leer"),
-			MonsterAttacks::Leafage	=> return write!(f, "leafage"),
-			MonsterAttacks::Ember	=> return write!(f, "ember"),
-			MonsterAttacks::Aquajet	=> return write!(f, "aquajet"),
+impl FromStr for AttackCategory {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"physical"	=> Ok(AttackCategory::Physical),
+			"special"	=> Ok(AttackCategory::Special),
+			"status"	=> Ok(AttackCategory::Status),
+			_			=> Err(()),
 		}
-    }
+	}
 }
 
-/// The abilities that monster possess.
-pub enum MonsterAbilities {
-	None,
+/// The stats that can be raised or lowered in stages during battle, in the order of ``Monster::statChanges``.
+#[derive(Clone, Copy, PartialEq)]
+pub enum BattleStat {
+	PhysicalAttack	= 0,
+	PhysicalDefense	= 1,
+	SpecialAttack	= 2,
+	SpecialDefense	= 3,
+	Speed			= 4,
+}
+impl Display for BattleStat {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			BattleStat::PhysicalAttack	=> return write!(f, "physical_attack"),
+			BattleStat::PhysicalDefense	=> return write!(f, "physical_defense"),
+			BattleStat::SpecialAttack	=> return write!(f, "special_attack"),
+			BattleStat::SpecialDefense	=> return write!(f, "special_defense"),
+			BattleStat::Speed			=> return write!(f, "speed"),
+		}
+	}
+}
+impl FromStr for BattleStat {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"physical_attack"	=> Ok(BattleStat::PhysicalAttack),
+			"physical_defense"	=> Ok(BattleStat::PhysicalDefense),
+			"special_attack"	=> Ok(BattleStat::SpecialAttack),
+			"special_defense"	=> Ok(BattleStat::SpecialDefense),
+			"speed"				=> Ok(BattleStat::Speed),
+			_					=> Err(()),
+		}
+	}
 }
 
 /// The various condtions that a monster can have.
+#[derive(Clone, PartialEq)]
 pub enum MonsterConditions {
 	None,
+	/// Deals damage at the end of each of the monster's turns, scaled by stacks.
+	/// <br>Loses a stack at the end of each round.
 	Burned{ stacks: i32, },
 }
+impl Display for MonsterConditions {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			MonsterConditions::None			=> return write!(f, ""),
+			MonsterConditions::Burned{..}	=> return write!(f, "burned"),
+		}
+	}
+}
 
 /// The experience needed to level up.
 #[derive(Clone, Copy)]
@@ -113,10 +134,74 @@ pub enum MonsterGrowthRate {
 	Erratic,
 	Fluctuating,
 }
+impl FromStr for MonsterGrowthRate {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"fast"			=> Ok(MonsterGrowthRate::Fast),
+			"medium_fast"	=> Ok(MonsterGrowthRate::MediumFast),
+			"medium_slow"	=> Ok(MonsterGrowthRate::MediumSlow),
+			"slow"			=> Ok(MonsterGrowthRate::Slow),
+			"erratic"		=> Ok(MonsterGrowthRate::Erratic),
+			"fluctuating"	=> Ok(MonsterGrowthRate::Fluctuating),
+			_				=> Err(()),
+		}
+	}
+}
 
 
 //= Structures
 
+/// An attack a monster can use in battle, an ID into ``attacks.json``.
+#[derive(Clone, PartialEq)]
+pub struct MonsterAttacks(pub String);
+impl Display for MonsterAttacks {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		return write!(f, "{}", self.0);
+	}
+}
+impl FromStr for MonsterAttacks {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s == "empty" { return Ok(MonsterAttacks::None); }
+		if s != "" && data::ATTACKS.contains_key(s) { return Ok(MonsterAttacks(s.to_string())); }
+		return Err(());
+	}
+}
+
+/// The ability a monster possesses, an ID into ``abilities.json``.
+#[derive(Clone, PartialEq)]
+pub struct MonsterAbilities(pub String);
+impl Display for MonsterAbilities {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		return write!(f, "{}", self.0);
+	}
+}
+impl FromStr for MonsterAbilities {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s == "none" { return Ok(MonsterAbilities::None); }
+		if s != "" && data::ABILITIES.contains_key(s) { return Ok(MonsterAbilities(s.to_string())); }
+		return Err(());
+	}
+}
+
+/// The monster's species, an ID into ``species.json``.
+#[derive(Clone, PartialEq)]
+pub struct MonsterSpecies(pub String);
+impl Display for MonsterSpecies {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		return write!(f, "{}", self.0);
+	}
+}
+impl FromStr for MonsterSpecies {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s != "" && data::SPECIES.contains_key(s) { return Ok(MonsterSpecies(s.to_string())); }
+		return Err(());
+	}
+}
+
 /// The structure of a monster team.
 #[derive(Clone)]
 pub struct MonsterTeam(pub [Option<Monster>;4]);
@@ -155,15 +240,49 @@ pub struct Monster {
 	pub specialDefense: i32,
 	pub speed: i32,
 
-	pub statChanges: [u8;5],
+	pub nature: MonsterNature,
+	/// Individual values for each stat, ``0`` to ``31``.
+	pub ivs: [i32;6],
+	/// Effort values for each stat, ``0`` to ``252``.
+	pub evs: [i32;6],
+
+	/// Stat stages from ``-6`` to ``6``, indexed by ``BattleStat``.
+	pub statChanges: [i8;5],
 	pub flinch: bool, //???
+	/// Active conditions, at most one of each kind.
+	pub conditions: Vec<MonsterConditions>,
 
 	pub experience: i32,
 	pub level: i32,
 	pub growthRate: MonsterGrowthRate,
 
+	pub ability: MonsterAbilities,
 	pub attacks: [MonsterAttacks;4],
 }
+/// A snapshot of a monster's stats.
+#[derive(Clone, Copy)]
+pub struct MonsterStats {
+	pub hpMax: i32,
+	pub physicalAttack: i32,
+	pub physicalDefense: i32,
+	pub specialAttack: i32,
+	pub specialDefense: i32,
+	pub speed: i32,
+}
+
+/// A monster's level and stats from before and after leveling up.
+#[derive(Clone)]
+pub struct LevelUpResult {
+	pub levelBefore: i32,
+	pub levelAfter: i32,
+
+	pub before: MonsterStats,
+	pub after: MonsterStats,
+
+	/// Attacks the monster can learn at the levels it gained.
+	pub newAttacks: Vec<MonsterAttacks>,
+}
+
 impl Display for Monster {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		return write!(
@@ -243,8 +362,20 @@ impl MonsterTeam {
 
 impl Monster {
 
-	/// Create a new monster only using species and level.
-	pub fn new(species: MonsterSpecies, level: i32) -> Self {
+	/// Create a new monster using species and level.
+	/// <br>The nature and IVs are random unless given, the ability is picked from the species' list.
+	pub fn new(species: MonsterSpecies, level: i32, nature: Option<MonsterNature>, ivs: Option<[i32;6]>) -> Self {
+		return Monster::new_seeded(species, level, nature, ivs, &mut Random::from_time());
+	}
+
+	/// Creates a monster like ``new``, rolling anything left out with the input generator.
+	pub fn new_seeded(species: MonsterSpecies, level: i32, nature: Option<MonsterNature>, ivs: Option<[i32;6]>, rng: &mut Random) -> Self {
+		let nature = nature.unwrap_or(MonsterNature(rng.range(0, MonsterNature::COUNT as i32 - 1) as usize));
+		let ivs = match ivs {
+			Some(ivs)	=> ivs,
+			None		=> [rng.range(0, 31), rng.range(0, 31), rng.range(0, 31), rng.range(0, 31), rng.range(0, 31), rng.range(0, 31)],
+		};
+
 		let mut result = Monster {
 			species,
 			types: [MonsterTypes::None,MonsterTypes::None],
@@ -260,123 +391,173 @@ impl Monster {
 			specialDefense: 0,
 			speed: 0,
 
+			nature,
+			ivs,
+			evs: [0;6],
+
 			statChanges: [0,0,0,0,0],
 			flinch: false,
+			conditions: Vec::new(),
 
 			experience: 0,
 			level,
 			growthRate: MonsterGrowthRate::Fast,
 
-			//TODO Generate attacks from their attack list and level.
+			ability: MonsterAbilities::None,
 			attacks: [MonsterAttacks::None,MonsterAttacks::None,MonsterAttacks::None,MonsterAttacks::None],
 		};
 
 		result.generate_stats();
 
+		let abilities = &result.species.data().abilities;
+		if abilities.len() > 0 { result.ability = abilities[rng.range(0, abilities.len() as i32 - 1) as usize].clone(); }
+
 		return result;
 	}
 
 	/// Generate monster stats from a clean monster.
 	pub fn generate_stats(&mut self) {
-		match self.species {
-			MonsterSpecies::Mon152 => {
-				self.types = [MonsterTypes::Grass, MonsterTypes::None];
-
-				self.calculate_stats();
-
-				self.growthRate = MonsterGrowthRate::MediumSlow;
-				self.experience = experience_from_level(self.level, MonsterGrowthRate::MediumSlow);
-
-				// TODO
-				self.attacks = [
-					MonsterAttacks::Tackle,
-					MonsterAttacks::Growl,
-					MonsterAttacks::Leafage,
-					MonsterAttacks::None,
-				]
-			}
-			MonsterSpecies::Mon155 => {
-				self.types = [MonsterTypes::Fire, MonsterTypes::None];
+		let species = self.species.data();
 
-				self.calculate_stats();
+		self.types = species.types;
+		self.growthRate = species.growthRate;
 
-				self.growthRate = MonsterGrowthRate::MediumSlow;
-				self.experience = experience_from_level(self.level, MonsterGrowthRate::MediumSlow);
+		self.calculate_stats();
+		self.experience = experience_from_level(self.level, self.growthRate);
 
-				// TODO
-				self.attacks = [
-					MonsterAttacks::Tackle,
-					MonsterAttacks::Leer,
-					MonsterAttacks::Ember,
-					MonsterAttacks::None,
-				]
-			}
-			MonsterSpecies::Mon158 => {
-				self.types = [MonsterTypes::Water, MonsterTypes::None];
+		self.generate_attacks();
+	}
+
+	/// Sets the monster's attacks to the four most recent ones it would've learned at it's level.
+	pub fn generate_attacks(&mut self) {
+		let mut known: Vec<MonsterAttacks> = Vec::new();
+		for (level, attack) in self.species.data().learnset.iter() {
+			if *level > self.level { continue; }
+			if let Some(index) = known.iter().position(|a| a == attack) { known.remove(index); }
+			known.push(attack.clone());
+		}
+		while known.len() > 4 { known.remove(0); }
 
-				self.calculate_stats();
+		for i in 0..4 {
+			if i < known.len() { self.attacks[i] = known[i].clone(); }
+			else { self.attacks[i] = MonsterAttacks::None; }
+		}
+	}
 
-				self.growthRate = MonsterGrowthRate::MediumSlow;
-				self.experience = experience_from_level(self.level, MonsterGrowthRate::MediumSlow);
+	/// Returns the attacks the monster's species learns at exactly the input level.
+	pub fn get_new_attacks(&self, level: i32) -> Vec<MonsterAttacks> {
+		let mut output: Vec<MonsterAttacks> = Vec::new();
+		for (lvl, attack) in self.species.data().learnset.iter() {
+			if *lvl == level && !self.attacks.contains(attack) { output.push(attack.clone()); }
+		}
+		return output;
+	}
 
-				// TODO
-				self.attacks = [
-					MonsterAttacks::Scratch,
-					MonsterAttacks::Leer,
-					MonsterAttacks::Aquajet,
-					MonsterAttacks::None,
-				]
+	/// Puts the attack in the first empty slot.
+	/// <br>Returns false if all four slots are full.
+	pub fn learn_attack(&mut self, attack: MonsterAttacks) -> bool {
+		if self.attacks.contains(&attack) { return true; }
+		for i in 0..4 {
+			if self.attacks[i] == MonsterAttacks::None {
+				self.attacks[i] = attack;
+				return true;
 			}
 		}
+		return false;
 	}
 
 	/// Calculate the stats of a monster
 	pub fn calculate_stats(&mut self) {
-		match self.species {
-			MonsterSpecies::Mon152 => {
-				let health: i32 = stat_calculation_a(50, self.level);
-				let hpdiff = health - self.hpMax;
-				self.hpCur += hpdiff;
-				self.hpMax = health;
-
-				self.physicalAttack		= stat_calculation_b(45, self.level);
-				self.physicalDefense	= stat_calculation_b(65, self.level);
-				self.specialAttack		= stat_calculation_b(45, self.level);
-				self.specialDefense		= stat_calculation_b(65, self.level);
-				self.speed				= stat_calculation_b(45, self.level);
-			}
-			MonsterSpecies::Mon155 => {
-				let health: i32 = stat_calculation_a(35, self.level);
-				let hpdiff = health - self.hpMax;
-				self.hpCur += hpdiff;
-				self.hpMax = health;
-
-				self.physicalAttack		= stat_calculation_b(55, self.level);
-				self.physicalDefense	= stat_calculation_b(40, self.level);
-				self.specialAttack		= stat_calculation_b(60, self.level);
-				self.specialDefense		= stat_calculation_b(40, self.level);
-				self.speed				= stat_calculation_b(65, self.level);
-			}
-			MonsterSpecies::Mon158 => {
-				let health: i32 = stat_calculation_a(35, self.level);
-				let hpdiff = health - self.hpMax;
-				self.hpCur += hpdiff;
-				self.hpMax = health;
-
-				self.physicalAttack		= stat_calculation_b(55, self.level);
-				self.physicalDefense	= stat_calculation_b(40, self.level);
-				self.specialAttack		= stat_calculation_b(60, self.level);
-				self.specialDefense		= stat_calculation_b(40, self.level);
-				self.speed				= stat_calculation_b(65, self.level);
-			}
+		let baseStats = self.species.data().baseStats;
+
+		let health: i32 = stat_calculation_a(baseStats.health, self.ivs[0], self.evs[0], self.level);
+		let hpdiff = health - self.hpMax;
+		self.hpCur += hpdiff;
+		self.hpMax = health;
+
+		self.physicalAttack		= stat_calculation_b(baseStats.physicalAttack, self.ivs[1], self.evs[1], self.level, self.nature.modifier(NatureStat::PhysicalAttack));
+		self.physicalDefense	= stat_calculation_b(baseStats.physicalDefense, self.ivs[2], self.evs[2], self.level, self.nature.modifier(NatureStat::PhysicalDefense));
+		self.specialAttack		= stat_calculation_b(baseStats.specialAttack, self.ivs[3], self.evs[3], self.level, self.nature.modifier(NatureStat::SpecialAttack));
+		self.specialDefense		= stat_calculation_b(baseStats.specialDefense, self.ivs[4], self.evs[4], self.level, self.nature.modifier(NatureStat::SpecialDefense));
+		self.speed				= stat_calculation_b(baseStats.speed, self.ivs[5], self.evs[5], self.level, self.nature.modifier(NatureStat::Speed));
+	}
+
+	/// Adds effort values, capped at 252 per stat and 510 total.
+	pub fn give_effort(&mut self, evs: [i32;6]) {
+		for i in 0..6 {
+			let total: i32 = self.evs.iter().sum();
+			let amount = evs[i].min(252 - self.evs[i]).min(510 - total).max(0);
+			self.evs[i] += amount;
 		}
 	}
 
-	/// Give a monster experience and check if it levels up.
-	pub fn give_experience(&mut self, experience: i32) -> bool {
+	/// Give a monster experience and level it up if it has enough.
+	pub fn give_experience(&mut self, experience: i32) -> Option<LevelUpResult> {
 		self.experience += experience;
 
-		return self.check_for_level();
+		return self.level_up();
+	}
+
+	/// Raises the monster's level for as long as it has enough experience and recalculates it's stats.
+	/// <br>Returns ``None`` if the monster didn't level up.
+	pub fn level_up(&mut self) -> Option<LevelUpResult> {
+		if !self.check_for_level() || self.level >= 100 { return None; }
+
+		let levelBefore = self.level;
+		let before = self.get_stats();
+		let mut newAttacks: Vec<MonsterAttacks> = Vec::new();
+
+		while self.check_for_level() && self.level < 100 {
+			self.level += 1;
+			newAttacks.append(&mut self.get_new_attacks(self.level));
+		}
+		self.calculate_stats();
+
+		return Some(LevelUpResult {
+			levelBefore,
+			levelAfter: self.level,
+
+			before,
+			after: self.get_stats(),
+
+			newAttacks,
+		});
+	}
+
+	/// Returns a snapshot of the monster's current stats.
+	pub fn get_stats(&self) -> MonsterStats {
+		return MonsterStats {
+			hpMax: self.hpMax,
+			physicalAttack: self.physicalAttack,
+			physicalDefense: self.physicalDefense,
+			specialAttack: self.specialAttack,
+			specialDefense: self.specialDefense,
+			speed: self.speed,
+		}
+	}
+
+	/// Returns the input stat with it's stage applied.
+	pub fn get_effective_stat(&self, stat: BattleStat) -> i32 {
+		let value = match stat {
+			BattleStat::PhysicalAttack	=> self.physicalAttack,
+			BattleStat::PhysicalDefense	=> self.physicalDefense,
+			BattleStat::SpecialAttack	=> self.specialAttack,
+			BattleStat::SpecialDefense	=> self.specialDefense,
+			BattleStat::Speed			=> self.speed,
+		};
+		return (value as f32 * stage_multiplier(self.statChanges[stat as usize]) * self.ability.data().stat_multiplier(stat)).floor() as i32;
+	}
+
+	/// Raises or lowers the stage of a stat and returns how much it actually changed.
+	pub fn change_stat_stage(&mut self, stat: BattleStat, amount: i8) -> i8 {
+		let before = self.statChanges[stat as usize];
+		self.statChanges[stat as usize] = before.saturating_add(amount).clamp(-6, 6);
+		return self.statChanges[stat as usize] - before;
+	}
+
+	/// Returns all stats to stage ``0``.
+	pub fn reset_stat_stages(&mut self) {
+		self.statChanges = [0,0,0,0,0];
 	}
 
 	/// Checks if the current amount of experience would level up the monster.
@@ -393,17 +574,146 @@ impl Monster {
 		else { return (self.species.to_string(), false); }
 	}
 
+	/// Removes health from the monster and returns whether it fainted.
+	pub fn take_damage(&mut self, damage: i32) -> bool {
+		self.hpCur -= damage;
+		if self.hpCur < 0 { self.hpCur = 0; }
+
+		return self.hpCur == 0;
+	}
+
+	/// Returns whether the monster has no health left.
+	pub fn is_fainted(&self) -> bool {
+		return self.hpCur <= 0;
+	}
+
+	/// Adds a condition to the monster, stacking it onto an existing one of the same kind.
+	pub fn add_condition(&mut self, condition: MonsterConditions) {
+		match condition {
+			MonsterConditions::None => return,
+			MonsterConditions::Burned { stacks } => {
+				for existing in self.conditions.iter_mut() {
+					if let MonsterConditions::Burned { stacks: current } = existing {
+						*current += stacks;
+						return;
+					}
+				}
+			}
+		}
+		self.conditions.push(condition);
+	}
+
+	/// Applies end of turn condition damage and returns the total dealt.
+	pub fn tick_conditions(&mut self) -> i32 {
+		let mut total = 0;
+		for condition in self.conditions.clone() {
+			match condition {
+				MonsterConditions::Burned { stacks } => {
+					total += ((self.hpMax as f32 / 16.0).floor() as i32).max(1) * stacks;
+				}
+				MonsterConditions::None => {}
+			}
+		}
+		if total > 0 { self.take_damage(total); }
+
+		return total;
+	}
+
+	/// Removes a stack from each condition at the end of a round, dropping ones that run out.
+	pub fn decay_conditions(&mut self) {
+		for condition in self.conditions.iter_mut() {
+			match condition {
+				MonsterConditions::Burned { stacks } => *stacks -= 1,
+				MonsterConditions::None => {}
+			}
+		}
+		self.conditions.retain(|c| match c {
+			MonsterConditions::Burned { stacks } => *stacks > 0,
+			MonsterConditions::None => false,
+		});
+	}
+
+}
+
+impl MonsterStats {
+
+	/// Returns the stats as an array, in the order they're displayed.
+	pub fn to_array(&self) -> [i32;6] {
+		return [self.hpMax, self.physicalAttack, self.physicalDefense, self.specialAttack, self.specialDefense, self.speed];
+	}
+
 }
 
+impl LevelUpResult {
+
+	/// Combines a later level up into this one, keeping the earliest stats.
+	pub fn merge(&mut self, later: LevelUpResult) {
+		self.levelAfter = later.levelAfter;
+		self.after = later.after;
+		self.newAttacks.append(&mut later.newAttacks.clone());
+	}
+
+}
+
+impl MonsterSpecies {
+
+	/// Returns the definition of the species from ``species.json``.
+	pub fn data(&self) -> &'static species::Species {
+		return data::get_species(&self.0);
+	}
+
+}
+
+impl MonsterAttacks {
+
+	/// The empty attack slot.
+	#[allow(non_upper_case_globals)]
+	pub const None: MonsterAttacks = MonsterAttacks(String::new());
+
+	/// Returns the definition of the attack from ``attacks.json``.
+	pub fn data(&self) -> &'static attacks::Attack {
+		return data::get_attack(&self.to_string());
+	}
+
+}
+
+impl MonsterAbilities {
+
+	/// No ability.
+	#[allow(non_upper_case_globals)]
+	pub const None: MonsterAbilities = MonsterAbilities(String::new());
+
+	/// Returns the definition of the ability from ``abilities.json``.
+	pub fn data(&self) -> &'static abilities::Ability {
+		return data::get_ability(&self.to_string());
+	}
+
+}
+
+impl MonsterTypes {
+
+	/// Returns the damage multiplier of an attack of this type against the input defending types.
+	pub fn effectiveness(&self, defendTypes: [MonsterTypes;2]) -> f32 {
+		return data::TYPE_CHART.multiplier(*self, defendTypes);
+	}
+
+}
+
+
+/// Returns the multiplier for a stat stage from ``-6`` to ``6``.
+pub fn stage_multiplier(stage: i8) -> f32 {
+	let stage = stage.clamp(-6, 6) as f32;
+	if stage >= 0.0 { return (2.0 + stage) / 2.0; }
+	else { return 2.0 / (2.0 - stage); }
+}
 
 /// Calculates health or stamina.
-//TODO IVs, EVs, and natures
-pub fn stat_calculation_a(baseStat: i32, level: i32) -> i32 {
-	return (((2.0 * baseStat as f32) * level as f32) / 100.0).floor() as i32 + level + 10;
+pub fn stat_calculation_a(baseStat: i32, iv: i32, ev: i32, level: i32) -> i32 {
+	return ((((2.0 * baseStat as f32) + iv as f32 + (ev as f32 / 4.0).floor()) * level as f32) / 100.0).floor() as i32 + level + 10;
 }
 /// Calculates the stats other than health and stamina
-pub fn stat_calculation_b(baseStat: i32, level: i32) -> i32 {
-	return (((((2.0 * baseStat as f32) * level as f32) / 100.0).floor() + 5.0) * 1.0).floor() as i32;
+pub fn stat_calculation_b(baseStat: i32, iv: i32, ev: i32, level: i32, nature: f32) -> i32 {
+	return ((((((2.0 * baseStat as f32) + iv as f32 + (ev as f32 / 4.0).floor()) * level as f32) / 100.0).floor() + 5.0) * nature).floor() as i32;
 }
 
 /// Calculates the amount of experience at a minimum a monster can have at input level.
@@ -441,4 +751,85 @@ pub fn experience_from_level(level: i32, growth: MonsterGrowthRate) -> i32 {
 			return 0;
 		}
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+pub mod tests {
+	use super::*;
+
+	/// Creates a monster with a neutral nature and fixed IVs, so tests don't depend on random rolls.
+	pub fn create_monster(species: &str, level: i32) -> Monster {
+		return Monster::new_seeded(MonsterSpecies::from_str(species).unwrap(), level, Some(MonsterNature(0)), Some([10;6]), &mut Random::new(1));
+	}
+
+	/// Returns the attack with the input ID.
+	pub fn attack(id: &str) -> MonsterAttacks {
+		return MonsterAttacks::from_str(id).unwrap();
+	}
+
+	#[test]
+	fn experience_levels_up() {
+		let mut monster = create_monster("mon_155", 10);
+		let before = monster.get_stats();
+		assert!(monster.give_experience(1).is_none());
+
+		let needed = experience_from_level(12, monster.growthRate) - monster.experience;
+		let result = monster.give_experience(needed).unwrap();
+		assert_eq!((result.levelBefore, result.levelAfter, monster.level), (10, 12, 12));
+		assert_eq!(result.before.hpMax, before.hpMax);
+		assert_eq!(result.after.hpMax, monster.hpMax);
+		assert!(monster.hpMax > before.hpMax && monster.speed >= before.speed);
+		assert!(monster.give_experience(0).is_none());
+	}
+
+	#[test]
+	fn level_up_learns_attacks() {
+		let mut monster = create_monster("mon_155", 4);
+		let needed = experience_from_level(5, monster.growthRate) - monster.experience;
+		let result = monster.give_experience(needed).unwrap();
+		assert!(result.newAttacks.contains(&attack("ember")));
+	}
+
+	#[test]
+	fn effort_is_capped() {
+		let mut monster = create_monster("mon_155", 50);
+		monster.give_effort([300, 0, 0, 0, 0, 0]);
+		assert_eq!(monster.evs[0], 252);
+
+		monster.give_effort([0, 252, 252, 0, 0, 0]);
+		assert_eq!(monster.evs, [252, 252, 6, 0, 0, 0]);
+		assert_eq!(monster.evs.iter().sum::<i32>(), 510);
+
+		monster.give_effort([0, 0, 0, 10, 10, 10]);
+		assert_eq!(monster.evs.iter().sum::<i32>(), 510);
+	}
+
+	#[test]
+	fn effort_raises_stats() {
+		let mut monster = create_monster("mon_155", 50);
+		let before = monster.speed;
+		monster.give_effort([0, 0, 0, 0, 0, 252]);
+		monster.calculate_stats();
+		assert!(monster.speed > before);
+	}
+
+	#[test]
+	fn natures_modify_stats() {
+		let neutral = create_monster("mon_155", 50);
+		let nature = MonsterNature::from_str("timid").unwrap();
+		let timid = Monster::new(MonsterSpecies::from_str("mon_155").unwrap(), 50, Some(nature), Some([10;6]));
+		assert!(timid.speed > neutral.speed);
+		assert!(timid.physicalAttack < neutral.physicalAttack);
+		assert_eq!(timid.hpMax, neutral.hpMax);
+	}
+
+	#[test]
+	fn level_is_capped() {
+		let mut monster = create_monster("mon_155", 99);
+		let result = monster.give_experience(i32::MAX / 2).unwrap();
+		assert_eq!(result.levelAfter, 100);
+		assert!(monster.give_experience(1000).is_none());
+		assert_eq!(monster.level, 100);
+	}
+
+}
@@ -0,0 +1,73 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{fmt::Display, str::FromStr};
+
+
+//= Constants
+/// Nature names, ordered so that ``index / 5`` is the raised stat and ``index % 5`` is the lowered one.
+const NAMES: [&str;25] = [
+	"hardy",	"lonely",	"brave",	"adamant",	"naughty",
+	"bold",		"docile",	"relaxed",	"impish",	"lax",
+	"timid",	"hasty",	"serious",	"jolly",	"naive",
+	"modest",	"mild",		"quiet",	"bashful",	"rash",
+	"calm",		"gentle",	"sassy",	"careful",	"quirky",
+];
+
+
+//= Enumerations
+
+/// The stats a nature can change, in the order used by ``NAMES``.
+#[derive(Clone, Copy, PartialEq)]
+pub enum NatureStat {
+	PhysicalAttack	= 0,
+	PhysicalDefense	= 1,
+	Speed			= 2,
+	SpecialAttack	= 3,
+	SpecialDefense	= 4,
+}
+
+
+//= Structures
+
+/// The nature of a monster, raising one stat by 10% and lowering another by 10%.
+#[derive(Clone, Copy, PartialEq)]
+pub struct MonsterNature(pub usize);
+impl Display for MonsterNature {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		return write!(f, "{}", NAMES[self.0 % 25]);
+	}
+}
+impl FromStr for MonsterNature {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match NAMES.iter().position(|n| *n == s) {
+			Some(index)	=> Ok(MonsterNature(index)),
+			None		=> Err(()),
+		}
+	}
+}
+
+
+//= Procedures
+
+impl MonsterNature {
+
+	/// The number of natures.
+	pub const COUNT: usize = 25;
+
+	/// Returns the multiplier the nature applies to the input stat.
+	pub fn modifier(&self, stat: NatureStat) -> f32 {
+		let raised = (self.0 % 25) / 5;
+		let lowered = (self.0 % 25) % 5;
+
+		if raised == lowered { return 1.0; }
+		if raised == stat as usize { return 1.1; }
+		if lowered == stat as usize { return 0.9; }
+		return 1.0;
+	}
+
+}
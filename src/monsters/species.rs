@@ -0,0 +1,181 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{collections::HashMap, fs::read_to_string, str::FromStr};
+
+use crate::utilities::debug;
+use super::{MonsterTypes, MonsterGrowthRate, MonsterAttacks, MonsterAbilities};
+
+
+//= Enumerations
+
+/// The physical size of a monster.
+#[derive(Clone, Copy, PartialEq)]
+pub enum MonsterSize {
+	Small,
+	Medium,
+	Large,
+}
+impl FromStr for MonsterSize {
+	type Err = ();
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"small"		=> Ok(MonsterSize::Small),
+			"medium"	=> Ok(MonsterSize::Medium),
+			"large"		=> Ok(MonsterSize::Large),
+			_			=> Err(()),
+		}
+	}
+}
+
+
+//= Structures
+
+/// The base stats of a species.
+#[derive(Clone, Copy)]
+pub struct BaseStats {
+	pub health: i32,
+	pub physicalAttack: i32,
+	pub physicalDefense: i32,
+	pub specialAttack: i32,
+	pub specialDefense: i32,
+	pub speed: i32,
+}
+
+/// The definition of a species.
+#[derive(Clone)]
+pub struct Species {
+	pub id: String,
+
+	pub types: [MonsterTypes;2],
+	pub baseStats: BaseStats,
+	pub growthRate: MonsterGrowthRate,
+	pub size: MonsterSize,
+	/// How easy the species is to capture, ``1`` to ``255``.
+	pub catchRate: i32,
+	/// Base experience given for defeating the species.
+	pub expYield: i32,
+	/// Effort values given for defeating the species, in the same order as ``Monster::evs``.
+	pub evYield: [i32;6],
+
+	/// Attacks learned and the level they're learned at, in order.
+	pub learnset: Vec<(i32, MonsterAttacks)>,
+	/// Abilities a monster of the species can have, one is picked when it's created.
+	pub abilities: Vec<MonsterAbilities>,
+}
+
+
+//= Procedures
+
+impl Species {
+
+	/// Creates the definition used for species that don't exist.
+	pub fn empty() -> Self {
+		return Species {
+			id: "".to_string(),
+
+			types: [MonsterTypes::None, MonsterTypes::None],
+			baseStats: BaseStats {
+				health: 0,
+				physicalAttack: 0,
+				physicalDefense: 0,
+				specialAttack: 0,
+				specialDefense: 0,
+				speed: 0,
+			},
+			growthRate: MonsterGrowthRate::MediumFast,
+			size: MonsterSize::Small,
+			catchRate: 0,
+			expYield: 0,
+			evYield: [0;6],
+
+			learnset: Vec::new(),
+			abilities: Vec::new(),
+		}
+	}
+
+}
+
+/// Loads all species definitions into a Hashmap indexed by their ID.
+pub fn load() -> HashMap<String, Species> {
+	let mut output: HashMap<String, Species> = HashMap::new();
+	output.insert("".to_string(), Species::empty());
+
+	//* Attempt to load species file */
+	let fileResult = read_to_string("data/monsters/species.json");
+	if fileResult.is_err() {
+		debug::log("[ERROR] - Failed to load species file.\n");
+		return output;
+	}
+
+	//* Convert to JSON and read */
+	let jsonFile: serde_json::Value = serde_json::from_str(&fileResult.unwrap()).unwrap();
+	for i in jsonFile["species"].as_array().unwrap() {
+		let id = i["id"].as_str().unwrap().to_string();
+
+		let type1 = MonsterTypes::from_str(i["types"].as_array().unwrap()[0].as_str().unwrap());
+		let type2 = MonsterTypes::from_str(i["types"].as_array().unwrap()[1].as_str().unwrap());
+		let growthRate = MonsterGrowthRate::from_str(i["growth"].as_str().unwrap());
+		let size = MonsterSize::from_str(i["size"].as_str().unwrap());
+		if type1.is_err() || type2.is_err() || growthRate.is_err() || size.is_err() {
+			debug::log(&format!("[ERROR] - Species \"{}\" has an invalid definition.\n", id));
+			continue;
+		}
+
+		let mut learnset: Vec<(i32, MonsterAttacks)> = Vec::new();
+		for o in i["learnset"].as_array().unwrap() {
+			let attack = MonsterAttacks::from_str(o.as_array().unwrap()[1].as_str().unwrap());
+			if attack.is_err() {
+				debug::log(&format!("[ERROR] - Species \"{}\" has an invalid attack in it's learnset.\n", id));
+				continue;
+			}
+			learnset.push((o.as_array().unwrap()[0].as_i64().unwrap() as i32, attack.unwrap()));
+		}
+
+		let mut abilities: Vec<MonsterAbilities> = Vec::new();
+		for o in i["abilities"].as_array().unwrap_or(&Vec::new()) {
+			let ability = MonsterAbilities::from_str(o.as_str().unwrap_or(""));
+			if ability.is_err() {
+				debug::log(&format!("[ERROR] - Species \"{}\" has an invalid ability.\n", id));
+				continue;
+			}
+			abilities.push(ability.unwrap());
+		}
+
+		let mut evYield = [0;6];
+		for (index, value) in i["ev_yield"].as_array().unwrap_or(&Vec::new()).iter().take(6).enumerate() {
+			evYield[index] = value.as_i64().unwrap_or(0) as i32;
+		}
+
+		let stats = &i["stats"];
+		output.insert(
+			id.to_string(),
+			Species {
+				id,
+
+				types: [type1.unwrap(), type2.unwrap()],
+				baseStats: BaseStats {
+					health:				stats["health"].as_i64().unwrap() as i32,
+					physicalAttack:		stats["physical_attack"].as_i64().unwrap() as i32,
+					physicalDefense:	stats["physical_defense"].as_i64().unwrap() as i32,
+					specialAttack:		stats["special_attack"].as_i64().unwrap() as i32,
+					specialDefense:		stats["special_defense"].as_i64().unwrap() as i32,
+					speed:				stats["speed"].as_i64().unwrap() as i32,
+				},
+				growthRate: growthRate.unwrap(),
+				size: size.unwrap(),
+				catchRate: i["catch_rate"].as_i64().unwrap_or(45) as i32,
+				expYield: i["exp_yield"].as_i64().unwrap_or(0) as i32,
+				evYield,
+
+				learnset,
+				abilities,
+			},
+		);
+	}
+
+	return output;
+}
@@ -0,0 +1,99 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::{collections::HashMap, fs::read_to_string, str::FromStr};
+
+use crate::utilities::debug;
+use super::MonsterTypes;
+
+
+//= Enumerations
+
+/// How effective an attack is against the types of it's target.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Effectiveness {
+	Immune,
+	NotVeryEffective,
+	Normal,
+	SuperEffective,
+}
+impl From<f32> for Effectiveness {
+	fn from(value: f32) -> Self {
+		if value <= 0.0 { return Effectiveness::Immune; }
+		if value < 1.0 { return Effectiveness::NotVeryEffective; }
+		if value > 1.0 { return Effectiveness::SuperEffective; }
+		return Effectiveness::Normal;
+	}
+}
+
+
+//= Structures
+
+/// Damage multipliers for attacking types against defending types.
+/// <br>Pairs that aren't listed deal normal damage.
+#[derive(Clone)]
+pub struct TypeChart(pub HashMap<(MonsterTypes, MonsterTypes), f32>);
+
+
+//= Procedures
+
+impl Effectiveness {
+
+	/// Returns the localization key of the message shown after the attack, if there is one.
+	pub fn message(&self) -> Option<&'static str> {
+		match self {
+			Effectiveness::Immune			=> return Some("battle_effective_immune"),
+			Effectiveness::NotVeryEffective	=> return Some("battle_effective_not_very"),
+			Effectiveness::Normal			=> return None,
+			Effectiveness::SuperEffective	=> return Some("battle_effective_super"),
+		}
+	}
+
+}
+
+impl TypeChart {
+
+	/// Returns the multiplier for an attack of the input type against a single defending type.
+	pub fn get(&self, attackType: MonsterTypes, defendType: MonsterTypes) -> f32 {
+		if attackType == MonsterTypes::None || defendType == MonsterTypes::None { return 1.0; }
+		return *self.0.get(&(attackType, defendType)).unwrap_or(&1.0);
+	}
+
+	/// Returns the combined multiplier for an attack of the input type against both of the defender's types.
+	pub fn multiplier(&self, attackType: MonsterTypes, defendTypes: [MonsterTypes;2]) -> f32 {
+		let mut output = self.get(attackType, defendTypes[0]);
+		if defendTypes[1] != defendTypes[0] { output *= self.get(attackType, defendTypes[1]); }
+		return output;
+	}
+
+}
+
+/// Loads the type chart.
+pub fn load() -> TypeChart {
+	let mut output = TypeChart(HashMap::new());
+
+	//* Attempt to load types file */
+	let fileResult = read_to_string("data/monsters/types.json");
+	if fileResult.is_err() {
+		debug::log("[ERROR] - Failed to load types file.\n");
+		return output;
+	}
+
+	//* Convert to JSON and read */
+	let jsonFile: serde_json::Value = serde_json::from_str(&fileResult.unwrap()).unwrap();
+	for i in jsonFile["chart"].as_array().unwrap() {
+		let attackType = MonsterTypes::from_str(i.as_array().unwrap()[0].as_str().unwrap());
+		let defendType = MonsterTypes::from_str(i.as_array().unwrap()[1].as_str().unwrap());
+		if attackType.is_err() || defendType.is_err() {
+			debug::log(&format!("[ERROR] - Type chart entry {} has an invalid type.\n", i));
+			continue;
+		}
+
+		output.0.insert((attackType.unwrap(), defendType.unwrap()), i.as_array().unwrap()[2].as_f64().unwrap() as f32);
+	}
+
+	return output;
+}
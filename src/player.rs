@@ -48,6 +48,9 @@ pub struct Player {
 	pub unit:		overworld::Unit,
 
 	pub monsters:	monsters::MonsterTeam,
+	/// Monsters that didn't fit in the team.
+	pub storage:	Vec<monsters::Monster>,
+	pub money:		i32,
 
 	pub canMove:	bool,
 	pub menu:		Menu,
@@ -76,6 +79,8 @@ impl Player {
 		let mut player = Player{
 			unit:		overworld::Unit::new(),
 			monsters:	monsters::MonsterTeam([None, None, None, None]),
+			storage:	Vec::new(),
+			money:		0,
 			canMove:	true,
 			menu:		Menu::init(),
 		};
@@ -87,6 +92,11 @@ impl Player {
 		return player;
 	}
 
+	/// Adds a monster to the team, or to storage if the team is full.
+	pub fn give_monster(&mut self, monster: monsters::Monster) {
+		if !self.monsters.add_member(monster.clone()) { self.storage.push(monster); }
+	}
+
 }
 
 impl Menu {
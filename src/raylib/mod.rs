@@ -6,7 +6,7 @@
 
 use crate::{data, camera::Camera};
 
-use self::vectors::Vector3;
+use self::{vectors::Vector3, textures::Texture};
 
 
 //= Imports
@@ -105,6 +105,20 @@ pub fn end_3d_mode() {
 	unsafe { raylib_ffi::EndMode3D(); }
 }
 
+pub fn draw_billboard( camera : &Camera, texture : Texture, position : Vector3, size : f32, tint : raylib_ffi::Color ) {
+	unsafe {
+		let rlCamera = raylib_ffi::Camera3D{
+			position:	camera.camPosition.into(),
+			target:		camera.position.into(),
+			up:			Vector3{x:0.0,y:1.0,z:0.0}.into(),
+			fovy:		camera.fovy,
+			projection:	raylib_ffi::enums::CameraProjection::Perspective as i32,
+		};
+
+		raylib_ffi::DrawBillboard(rlCamera, texture.into(), position.into(), size, tint);
+	}
+}
+
 pub fn draw_grid( slices : i32, spacing : f32 ) {
 	unsafe { raylib_ffi::DrawGrid(slices, spacing); }
 }
@@ -249,6 +263,9 @@ pub fn mouse_button_released( key : i32 ) -> bool {
 pub fn mouse_button_up( key : i32 ) -> bool {
 	unsafe { return raylib_ffi::IsMouseButtonUp(key); }
 }
+pub fn mouse_wheel_move() -> f32 {
+	unsafe { return raylib_ffi::GetMouseWheelMove(); }
+}
 
 pub fn gamepad_available( gamepad : i32 ) -> bool {
 	unsafe { return raylib_ffi::IsGamepadAvailable(gamepad); }
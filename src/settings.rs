@@ -123,6 +123,10 @@ pub struct Settings {
 	//* Game config */
 	// TODO Seperate this into seperate structure?
 	pub difficulty: Difficulty,
+	/// Records every battle to a replay file.
+	pub recordReplays: bool,
+	/// Saves the battle log of every battle to a text file.
+	pub saveBattleLogs: bool,
 }
 
 /// Storage for individual keybindings
@@ -164,6 +168,8 @@ impl Settings {
 		self.sfxVolume		= jsonFile["sound"].as_f64().unwrap() as f32;
 
 		self.difficulty		= Difficulty::from_str(jsonFile["difficulty"].as_str().unwrap()).unwrap();
+		self.recordReplays	= jsonFile["record_replays"].as_bool().unwrap_or(false);
+		self.saveBattleLogs	= jsonFile["save_battle_logs"].as_bool().unwrap_or(false);
 
 		self.keybindings = Some(HashMap::new());
 		for val in jsonFile["keybindings"].as_array().unwrap() {
@@ -189,6 +195,8 @@ impl Settings {
 
 		self.keybindings 	= Some(HashMap::new());
 		self.difficulty		= Difficulty::Medium;
+		self.recordReplays	= false;
+		self.saveBattleLogs	= false;
 
 		self.keybindings.as_mut().unwrap().insert("up".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 87 });
 		self.keybindings.as_mut().unwrap().insert("down".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 83 });
@@ -205,6 +213,9 @@ impl Settings {
 		self.keybindings.as_mut().unwrap().insert("attack_4".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 52 });
 		self.keybindings.as_mut().unwrap().insert("swap_modes".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 69 });
 		self.keybindings.as_mut().unwrap().insert("shift".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 340 });
+		self.keybindings.as_mut().unwrap().insert("flee".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 70 });
+		self.keybindings.as_mut().unwrap().insert("capture".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 67 });
+		self.keybindings.as_mut().unwrap().insert("switch".to_string(), Keybinding { origin: Origin::Keyboard, controller: 0, code: 88 });
 
 		self.masterVolume = 0.1;
 		self.musicVolume = 0.2;
@@ -228,6 +239,8 @@ impl Settings {
 		newSettingsFile.push_str(format!("\t\"music\": {},\n", self.musicVolume).as_str());
 		newSettingsFile.push_str(format!("\t\"sound\": {},\n", self.sfxVolume).as_str());
 		newSettingsFile.push_str(format!("\t\"difficulty\": \"{}\",\n", self.difficulty).as_str());
+		newSettingsFile.push_str(format!("\t\"record_replays\": {},\n", self.recordReplays).as_str());
+		newSettingsFile.push_str(format!("\t\"save_battle_logs\": {},\n", self.saveBattleLogs).as_str());
 		newSettingsFile.push_str("\t\"keybindings\": [\n");
 		for (str, key) in self.keybindings.as_ref().unwrap() {
 			if counter == self.keybindings.as_ref().unwrap().len()-1 {
@@ -3,4 +3,6 @@
 //= Imports
 pub mod debug;
 
-pub mod math;
\ No newline at end of file
+pub mod math;
+
+pub mod random;
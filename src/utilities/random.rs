@@ -0,0 +1,117 @@
+//= Allows
+#![allow(non_snake_case)]
+#![allow(dead_code)]
+
+
+//= Imports
+use std::sync::atomic::{AtomicU64, Ordering};
+use chrono;
+
+
+//= Globals
+/// Mixed into time based seeds so generators created at the same moment differ.
+static SEED_COUNTER : AtomicU64 = AtomicU64::new(0);
+
+
+//= Structures
+
+/// Seedable pseudo-random number generator.
+/// <br>The same seed always produces the same sequence.
+#[derive(Clone)]
+pub struct Random {
+	pub seed: u64,
+	state: u64,
+}
+
+
+//= Procedures
+
+impl Random {
+
+	/// Create generator from seed
+	pub fn new(seed: u64) -> Self {
+		return Random {
+			seed,
+			state: seed ^ 0x9E3779B97F4A7C15,
+		}
+	}
+
+	/// Create generator seeded from the current time
+	pub fn from_time() -> Self {
+		let now = chrono::Local::now().timestamp_nanos_opt().unwrap_or(0) as u64;
+		let counter = SEED_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+		return Random::new(now ^ counter.wrapping_mul(0x2545F4914F6CDD1D));
+	}
+
+	/// Returns the next number in the sequence.
+	pub fn next(&mut self) -> u64 {
+		//* SplitMix64 */
+		self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+		return z ^ (z >> 31);
+	}
+
+	/// Returns a number between ``min`` and ``max``, inclusive.
+	pub fn range(&mut self, min: i32, max: i32) -> i32 {
+		if max <= min { return min; }
+		let span = (max as i64 - min as i64 + 1) as u64;
+		return (min as i64 + (self.next() % span) as i64) as i32;
+	}
+
+	/// Returns a number from ``0.0`` up to but not including ``1.0``.
+	pub fn float(&mut self) -> f32 {
+		return (self.next() >> 40) as f32 / (1u64 << 24) as f32;
+	}
+
+	/// Returns true with the input chance out of ``1.0``.
+	pub fn chance(&mut self, chance: f32) -> bool {
+		return self.float() < chance;
+	}
+
+}
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn same_seed_same_sequence() {
+		let mut a = Random::new(42);
+		let mut b = Random::new(42);
+		for _ in 0..100 { assert_eq!(a.next(), b.next()); }
+
+		let mut c = Random::new(43);
+		let mut a = Random::new(42);
+		assert!((0..10).any(|_| a.next() != c.next()));
+	}
+
+	#[test]
+	fn range_is_inclusive() {
+		let mut rng = Random::new(7);
+		let mut seen = [false;6];
+		for _ in 0..1000 {
+			let value = rng.range(-2, 3);
+			assert!(value >= -2 && value <= 3);
+			seen[(value + 2) as usize] = true;
+		}
+		assert!(seen.iter().all(|s| *s));
+		assert_eq!(rng.range(5, 5), 5);
+		assert_eq!(rng.range(5, 1), 5);
+	}
+
+	#[test]
+	fn chance_limits() {
+		let mut rng = Random::new(3);
+		for _ in 0..1000 {
+			let value = rng.float();
+			assert!(value >= 0.0 && value < 1.0);
+			assert!(rng.chance(1.0));
+			assert!(!rng.chance(0.0));
+		}
+	}
+
+}
@@ -8,7 +8,7 @@
 //= Imports
 use std::{collections::HashMap, fs::read_to_string, str::FromStr};
 
-use crate::{utilities::debug, data::Gamestate, overworld, raylib::{self, vectors::Vector3}, events::{self, conditionals::Condition}, battle::{self, BattleType, ArenaType}, monsters};
+use crate::{utilities::{debug, random::Random}, data::Gamestate, overworld, raylib::{self, vectors::Vector3}, events::{self, conditionals::Condition}, battle::{self, BattleType, ArenaType}, monsters};
 
 
 //= Constants
@@ -214,6 +214,13 @@ impl World {
 	}
 	/// Loads battle data from input file to hashmap indexed by position.
 	pub fn load_battles(&mut self, mapName : &str) {
+		self.load_seeded_battles(mapName, Random::from_time().seed);
+	}
+	/// Loads battle data like ``load_battles``, rolling anything the data leaves out with the input seed.
+	/// <br>The same seed always gives the same teams.
+	pub fn load_seeded_battles(&mut self, mapName : &str, seed : u64) {
+		let mut rng = Random::new(seed);
+
 		//* Attempt to load entities file */
 		let fileResult_evt = read_to_string("data/world/".to_string() + mapName + "/battles.json" );
 		if fileResult_evt.is_err() {
@@ -224,34 +231,26 @@ impl World {
 		//* Convert to JSON and read */
 		let jsonFile_evt: serde_json::Value = serde_json::from_str(&fileResult_evt.unwrap()).unwrap();
 		for i in jsonFile_evt["battles"].as_array().unwrap() {
+			let battleID = i.as_object().unwrap()["id"].as_str().unwrap().to_string();
 			let battle: battle::BattleType;
 			match i.as_object().unwrap()["type"].as_str().unwrap() {
 				"single" => {
 					//* Easy team */
 					let mut easyTeam = monsters::MonsterTeam::new();
-					for b in i.as_object().unwrap()["mon_easy"].as_array().unwrap() {
-						easyTeam.add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_easy"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_easy[{}]", slot), &mut rng) { easyTeam.add_member(monster); }
 					}
 				
 					//* Medium team */
 					let mut mediumTeam = monsters::MonsterTeam::new();
-					for b in i.as_object().unwrap()["mon_medium"].as_array().unwrap() {
-						mediumTeam.add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_medium"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_medium[{}]", slot), &mut rng) { mediumTeam.add_member(monster); }
 					}
 				
 					//* Hard team */
 					let mut hardTeam = monsters::MonsterTeam::new();
-					for b in i.as_object().unwrap()["mon_hard"].as_array().unwrap() {
-						hardTeam.add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_hard"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_hard[{}]", slot), &mut rng) { hardTeam.add_member(monster); }
 					}
 
 					battle = battle::BattleType::Single {
@@ -260,52 +259,35 @@ impl World {
 						mediumTeam,
 						hardTeam,
 						arena: ArenaType::from(i.as_object().unwrap()["arena"].as_str().unwrap()),
+						reward: i["reward"].as_i64().unwrap_or(0) as i32,
 					};
 				}
 				"double" => {
 					//* Easy team */
 					let mut easyTeam = [monsters::MonsterTeam::new(),monsters::MonsterTeam::new()];
-					for b in i.as_object().unwrap()["mon_easy_1"].as_array().unwrap() {
-						easyTeam[0].add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_easy_1"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_easy_1[{}]", slot), &mut rng) { easyTeam[0].add_member(monster); }
 					}
-					for b in i.as_object().unwrap()["mon_easy_2"].as_array().unwrap() {
-						easyTeam[1].add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_easy_2"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_easy_2[{}]", slot), &mut rng) { easyTeam[1].add_member(monster); }
 					}
 				
 					//* Medium team */
 					let mut mediumTeam = [monsters::MonsterTeam::new(),monsters::MonsterTeam::new()];
-					for b in i.as_object().unwrap()["mon_medium_1"].as_array().unwrap() {
-						mediumTeam[0].add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_medium_1"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_medium_1[{}]", slot), &mut rng) { mediumTeam[0].add_member(monster); }
 					}
-					for b in i.as_object().unwrap()["mon_medium_2"].as_array().unwrap() {
-						mediumTeam[1].add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_medium_2"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_medium_2[{}]", slot), &mut rng) { mediumTeam[1].add_member(monster); }
 					}
 				
 					//* Hard team */
 					let mut hardTeam = [monsters::MonsterTeam::new(),monsters::MonsterTeam::new()];
-					for b in i.as_object().unwrap()["mon_hard_1"].as_array().unwrap() {
-						hardTeam[0].add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_hard_1"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_hard_1[{}]", slot), &mut rng) { hardTeam[0].add_member(monster); }
 					}
-					for b in i.as_object().unwrap()["mon_hard_2"].as_array().unwrap() {
-						hardTeam[1].add_member(monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(b.as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							b.as_array().unwrap()[1].as_i64().unwrap() as i32,
-						));
+					for (slot, b) in i.as_object().unwrap()["mon_hard_2"].as_array().unwrap().iter().enumerate() {
+						if let Some(monster) = parse_monster(b, &battleID, &format!("mon_hard_2[{}]", slot), &mut rng) { hardTeam[1].add_member(monster); }
 					}
 
 					let mut trainerName = ["".to_string(),"".to_string()];
@@ -325,17 +307,20 @@ impl World {
 						mediumTeam,
 						hardTeam,
 						arena: ArenaType::from(i.as_object().unwrap()["arena"].as_str().unwrap()),
+						reward: i["reward"].as_i64().unwrap_or(0) as i32,
 					};
 				}
 				"wild"	 => {
-					battle = battle::BattleType::Wild {
-    					monster: monsters::Monster::new(
-							monsters::MonsterSpecies::from_str(i.as_object().unwrap()["mon"].as_array().unwrap()[0].as_str().unwrap()).unwrap(),
-							i.as_object().unwrap()["mon"].as_array().unwrap()[1].as_i64().unwrap() as i32,
-						),
-
-						arena: ArenaType::from(i.as_object().unwrap()["arena"].as_str().unwrap()),
-    				};
+					match parse_monster(&i.as_object().unwrap()["mon"], &battleID, "mon", &mut rng) {
+						Some(monster) => {
+							battle = battle::BattleType::Wild {
+								monster,
+
+								arena: ArenaType::from(i.as_object().unwrap()["arena"].as_str().unwrap()),
+							};
+						}
+						None => battle = BattleType::Empty,
+					}
 				}
 				_ => {
 					battle = BattleType::Empty;
@@ -343,7 +328,7 @@ impl World {
 				}
 			}
 
-			self.battleList.insert(i.as_object().unwrap()["id"].as_str().unwrap().to_string(), battle);
+			self.battleList.insert(battleID, battle);
 		}
 	} 
 
@@ -379,6 +364,89 @@ impl World {
 
 }
 
+/// Converts input JSON value into a monster.
+/// <br>Expects ``[species, level, nature, attack x4]`` with an optional array of 6 IVs at index 7 and ability at index 8.
+/// <br>Every bad field is reported using the battle ID and slot. Bad levels are clamped, the nature and IVs are rolled and the default ability and attacks are kept.
+/// <br>Returns ``None`` if the species doesn't exist, since there's nothing to fall back to.
+pub fn parse_monster( value : &serde_json::Value, battleID : &str, slot : &str, rng : &mut Random ) -> Option<monsters::Monster> {
+	let empty: Vec<serde_json::Value> = Vec::new();
+	let array = value.as_array().unwrap_or(&empty);
+
+	//* Species */
+	let name = array.get(0).and_then(|s| s.as_str()).unwrap_or("");
+	let species = monsters::MonsterSpecies::from_str(name);
+	if species.is_err() { debug::log(&format!("[ERROR] - Battle \"{}\" {} has an unknown species \"{}\".\n", battleID, slot, name)); }
+
+	//* Level */
+	let mut level = array.get(1).and_then(|l| l.as_i64()).unwrap_or(1);
+	if !array.get(1).map(|l| l.is_i64()).unwrap_or(false) || level < 1 || level > 100 {
+		debug::log(&format!("[ERROR] - Battle \"{}\" {} has an invalid level {}.\n", battleID, slot, array.get(1).unwrap_or(&serde_json::Value::Null)));
+		level = level.clamp(1, 100);
+	}
+
+	//* Nature, rolled if it's left out or unknown */
+	let mut nature: Option<monsters::natures::MonsterNature> = None;
+	if array.len() > 2 {
+		let name = array[2].as_str().unwrap_or("");
+		match monsters::natures::MonsterNature::from_str(name) {
+			Ok(value)	=> nature = Some(value),
+			Err(_)		=> debug::log(&format!("[ERROR] - Battle \"{}\" {} has an unknown nature \"{}\".\n", battleID, slot, name)),
+		}
+	}
+
+	//* IVs, rolled if any of them are bad */
+	let mut ivs: Option<[i32;6]> = None;
+	if array.len() > 7 && array[7].is_array() {
+		let mut list = [0;6];
+		let mut valid = true;
+		for (index, iv) in array[7].as_array().unwrap().iter().take(6).enumerate() {
+			match iv.as_i64() {
+				Some(value)	=> list[index] = (value as i32).clamp(0, 31),
+				None		=> {
+					debug::log(&format!("[ERROR] - Battle \"{}\" {} has an invalid IV {} in slot {}.\n", battleID, slot, iv, index));
+					valid = false;
+				}
+			}
+		}
+		if valid { ivs = Some(list); }
+	}
+
+	//* Ability */
+	let mut ability: Option<monsters::MonsterAbilities> = None;
+	if array.len() > 8 {
+		let name = array[8].as_str().unwrap_or("");
+		match monsters::MonsterAbilities::from_str(name) {
+			Ok(value)	=> ability = Some(value),
+			Err(_)		=> debug::log(&format!("[ERROR] - Battle \"{}\" {} has an unknown ability \"{}\".\n", battleID, slot, name)),
+		}
+	}
+
+	//* Attacks, all four are kept from the learnset if any are unknown */
+	let mut attacks: Option<[monsters::MonsterAttacks;4]> = None;
+	if array.len() >= 7 {
+		let mut list = [monsters::MonsterAttacks::None,monsters::MonsterAttacks::None,monsters::MonsterAttacks::None,monsters::MonsterAttacks::None];
+		let mut valid = true;
+		for index in 0..4 {
+			let name = array[index + 3].as_str().unwrap_or("");
+			match monsters::MonsterAttacks::from_str(name) {
+				Ok(attack)	=> list[index] = attack,
+				Err(_)		=> {
+					debug::log(&format!("[ERROR] - Battle \"{}\" {} has an unknown attack \"{}\" in slot {}.\n", battleID, slot, name, index));
+					valid = false;
+				}
+			}
+		}
+		if valid { attacks = Some(list); }
+	}
+
+	if species.is_err() { return None; }
+	let mut monster = monsters::Monster::new_seeded(species.unwrap(), level as i32, nature, ivs, rng);
+	if ability.is_some() { monster.ability = ability.unwrap(); }
+	if attacks.is_some() { monster.attacks = attacks.unwrap(); }
+
+	return Some(monster);
+}
+
 /// Converts input JSON value into an array of 4 bools representing a collision box.
 pub fn solid_tag_to_bool( array : &Vec<serde_json::Value> ) -> [bool; 4] {
 	let mut output = [false, false, false, false];